@@ -0,0 +1,139 @@
+/*!
+Actions trigger the behaviors of a rendered chart, e.g. highlighting a series
+or moving a data zoom window, as if the user interacted with the chart. They
+are dispatched with `WasmRenderer::dispatch_action`.
+
+```rust
+use charming::action::{Action, ActionTarget, DataZoomAction};
+
+let highlight = Action::Highlight(ActionTarget::new().series_name("Sales").data_index(2));
+let zoom = Action::DataZoom(DataZoomAction::new().start(20).end(80));
+let legend = Action::LegendUnSelect { name: "Costs".to_string() };
+```
+*/
+
+use crate::{component::BrushType, datatype::CompositeValue};
+use charming_macros::CharmingSetters;
+use serde::{Deserialize, Serialize};
+
+/// An action of `echarts.dispatchAction`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Action {
+    /// Highlight the targeted series or data items.
+    Highlight(ActionTarget),
+    /// Cancel the highlight of the targeted series or data items.
+    Downplay(ActionTarget),
+    /// Select the targeted data items.
+    Select(ActionTarget),
+    /// Unselect the targeted data items.
+    Unselect(ActionTarget),
+    /// Toggle the selection of the targeted data items.
+    ToggleSelect(ActionTarget),
+    /// Show the tooltip of a data item or at a position.
+    ShowTip(ShowTipAction),
+    /// Hide the tooltip.
+    HideTip,
+    /// Move the window of a data zoom component.
+    DataZoom(DataZoomAction),
+    /// Select a legend item.
+    LegendSelect { name: String },
+    /// Unselect a legend item.
+    LegendUnSelect { name: String },
+    /// Toggle the selection of a legend item.
+    LegendToggleSelect { name: String },
+    /// Select all legend items.
+    LegendAllSelect,
+    /// Invert the selection of the legend items.
+    LegendInverseSelect,
+    /// Set the areas selected by the brush, or clear them with no areas.
+    Brush { areas: Vec<BrushArea> },
+    /// Switch the timeline to a frame.
+    #[serde(rename_all = "camelCase")]
+    TimelineChange { current_index: usize },
+    /// Select a region of a map series.
+    MapSelect(ActionTarget),
+}
+
+/// The series and data items targeted by an action. Data items are targeted
+/// by index or by name.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionTarget {
+    /// A series index or an array of series indices.
+    series_index: Option<CompositeValue>,
+    series_id: Option<String>,
+    series_name: Option<String>,
+    /// A data index or an array of data indices.
+    data_index: Option<CompositeValue>,
+    /// Name of the data item.
+    name: Option<String>,
+}
+
+/// Payload of [`Action::ShowTip`], either a data item or a position.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowTipAction {
+    series_index: Option<CompositeValue>,
+    data_index: Option<CompositeValue>,
+    /// Name of the data item.
+    name: Option<String>,
+    /// X position of the tooltip, in pixels.
+    x: Option<f64>,
+    /// Y position of the tooltip, in pixels.
+    y: Option<f64>,
+}
+
+/// Payload of [`Action::DataZoom`], the window either in percent or in data
+/// values.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DataZoomAction {
+    /// Index of the data zoom component, or an array of indices, all of
+    /// them by default.
+    data_zoom_index: Option<CompositeValue>,
+    data_zoom_id: Option<String>,
+    /// Start of the window, in percent.
+    start: Option<f64>,
+    /// End of the window, in percent.
+    end: Option<f64>,
+    /// Start of the window, in data values.
+    start_value: Option<CompositeValue>,
+    /// End of the window, in data values.
+    end_value: Option<CompositeValue>,
+}
+
+/// An area of [`Action::Brush`], given either in pixels with `range` or in
+/// the coordinates of a coordinate system with `coord_range`.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrushArea {
+    brush_type: Option<BrushType>,
+    /// The area in pixels, e.g. `[[x1, x2], [y1, y2]]` for a rectangle.
+    range: Option<CompositeValue>,
+    /// The area in coordinates, e.g. `[[x1, x2], [y1, y2]]` for a
+    /// rectangle.
+    coord_range: Option<CompositeValue>,
+    /// The x axis of `coord_range`.
+    x_axis_index: Option<CompositeValue>,
+    /// The y axis of `coord_range`.
+    y_axis_index: Option<CompositeValue>,
+    /// The geo component of `coord_range`.
+    geo_index: Option<CompositeValue>,
+}
@@ -1,20 +1,38 @@
 ((globalThis) => {
-    const core = Deno.core;
+    // Console messages are buffered and collected by the renderer after each render.
+    const messages = [];
 
     function argsToMessage(...args) {
-        return args.map((arg) => JSON.stringify(arg)).join(" ");
+        return args
+            .map((arg) => {
+                if (typeof arg === "string") {
+                    return arg;
+                }
+                if (arg instanceof Error) {
+                    return arg.stack || String(arg);
+                }
+                return JSON.stringify(arg);
+            })
+            .join(" ");
+    }
+
+    function record(level) {
+        return (...args) => {
+            messages.push({ level, message: argsToMessage(...args) });
+        };
     }
 
     globalThis.console = {
-        log: (...args) => {
-            core.print(`[out]: ${argsToMessage(...args)}\n`, false);
-        },
-        error: (...args) => {
-            core.print(`[err]: ${argsToMessage(...args)}\n`, true);
-        },
+        debug: record("debug"),
+        log: record("log"),
+        info: record("info"),
+        warn: record("warn"),
+        error: record("error"),
     }
 
+    globalThis.__charmingTakeConsoleMessages = () => messages.splice(0, messages.length);
+
     globalThis.global = {};
     globalThis.setTimeout = () => { };
     globalThis.clearTimeout = () => { };
-})(globalThis);
\ No newline at end of file
+})(globalThis);
@@ -1,13 +1,55 @@
-use crate::element::AxisType;
+use crate::{
+    datatype::CompositeValue,
+    element::{AxisLabel, AxisLine, AxisTick, AxisType, SplitArea, SplitLine, TextStyle},
+};
 use charming_macros::CharmingSetters;
 use serde::{Deserialize, Serialize};
 
+/// Axis of the 3D cartesian coordinate system.
 #[serde_with::apply(
   Option => #[serde(skip_serializing_if = "Option::is_none")],
   Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
 )]
-#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Axis3D {
+    /// Type of axis.
+    #[serde(rename = "type")]
     type_: Option<AxisType>,
+    /// Whether to show the axis.
+    show: Option<bool>,
+    /// Name of axis.
+    name: Option<String>,
+    /// Index of the grid3D which is used to place this axis.
+    #[serde(rename = "grid3DIndex")]
+    grid3d_index: Option<f64>,
+    /// Text style of axis name.
+    name_text_style: Option<TextStyle>,
+    /// Gap between axis name and axis line.
+    name_gap: Option<f64>,
+    /// The minimum value of axis.
+    min: Option<CompositeValue>,
+    /// The maximum value of axis.
+    max: Option<CompositeValue>,
+    scale: Option<bool>,
+    /// Number of segments that the axis is split into.
+    split_number: Option<f64>,
+    /// Minimum gap between split lines.
+    min_interval: Option<f64>,
+    /// Compulsively set segmentation interval for axis.
+    interval: Option<f64>,
+    /// Base of logarithm, which is valid only for numeric axes with `log` type.
+    log_base: Option<f64>,
+    /// Settings related to axis line.
+    axis_line: Option<AxisLine>,
+    /// Settings related to axis label.
+    axis_label: Option<AxisLabel>,
+    /// Settings related to axis tick.
+    axis_tick: Option<AxisTick>,
+    /// Settings related to split line.
+    split_line: Option<SplitLine>,
+    /// Settings related to split area.
+    split_area: Option<SplitArea>,
+    #[charming_set_vec]
+    data: Vec<String>,
 }
@@ -1 +1,110 @@
+use crate::{
+    datatype::CompositeValue,
+    element::{Color, ItemStyle, Symbol},
+};
+use charming_macros::CharmingSetters;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum BrushType {
+    /// Select a rectangle area.
+    Rect,
+    /// Select an arbitrary polygon area.
+    Polygon,
+    /// Select along the x direction only.
+    LineX,
+    /// Select along the y direction only.
+    LineY,
+    /// Toolbox button that switches between single and multiple mode.
+    Keep,
+    /// Toolbox button that clears all selections.
+    Clear,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum BrushMode {
+    /// Only one selection area at a time.
+    Single,
+    /// Multiple selection areas at a time.
+    Multiple,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum BrushThrottleType {
+    /// Trigger the `brushSelected` event only when the action has stopped
+    /// for `throttle_delay` milliseconds.
+    Debounce,
+    /// Trigger the `brushSelected` event at most once every
+    /// `throttle_delay` milliseconds.
+    FixRate,
+}
+
+/// Visual channels applied to the data items inside or outside of the
+/// selected areas.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrushVisual {
+    symbol: Option<Symbol>,
+    symbol_size: Option<f64>,
+    color: Option<Color>,
+    color_alpha: Option<f64>,
+    opacity: Option<f64>,
+    color_lightness: Option<f64>,
+    color_saturation: Option<f64>,
+    color_hue: Option<f64>,
+}
+
+/// Brush is an area-selection component, with which user can select part of
+/// data from a chart to display in detail, or do calculations with them.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Brush {
+    /// Component ID.
+    id: Option<String>,
+    /// Buttons shown in the toolbox to switch the brush type.
+    #[charming_set_vec]
+    toolbox: Vec<BrushType>,
+    /// Links interaction between selected items in different series. Either
+    /// `"all"`, `"none"` or an array of series indices.
+    brush_link: Option<CompositeValue>,
+    /// Series that can be selected. Either `"all"`, `"none"`, a series index
+    /// or an array of series indices.
+    series_index: Option<CompositeValue>,
+    /// Geo components that can be selected.
+    geo_index: Option<CompositeValue>,
+    /// X axes that can be selected.
+    x_axis_index: Option<CompositeValue>,
+    /// Y axes that can be selected.
+    y_axis_index: Option<CompositeValue>,
+    /// Default type of the brush.
+    brush_type: Option<BrushType>,
+    /// Default mode of the brush.
+    brush_mode: Option<BrushMode>,
+    /// Whether the selected area can be dragged and resized.
+    transformable: Option<bool>,
+    /// Style of the selected area.
+    brush_style: Option<ItemStyle>,
+    /// Rate limit policy of the `brushSelected` event.
+    throttle_type: Option<BrushThrottleType>,
+    /// Delay of the rate limit, in milliseconds.
+    throttle_delay: Option<f64>,
+    /// Whether to remove all selected areas when clicking on the chart.
+    remove_on_click: Option<bool>,
+    /// Visual channels of the selected data items.
+    in_brush: Option<BrushVisual>,
+    /// Visual channels of the unselected data items.
+    out_of_brush: Option<BrushVisual>,
+    /// The `z` value of the selected areas.
+    z: Option<f64>,
+}
@@ -1,10 +1,17 @@
 use crate::{
     datatype::CompositeValue,
-    element::{Blur, Emphasis, ItemStyle, Label, Select},
+    element::{Blur, Emphasis, ItemStyle, Label, ScaleLimit, Select},
 };
 use charming_macros::CharmingSetters;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
+/// Geographic coordinate system component, used to draw maps and to place
+/// scatter and lines series on them.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
 #[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Geo {
@@ -17,10 +24,9 @@ pub struct Geo {
     #[charming_skip_setter]
     bounding_coords: Option<((String, String), (String, String))>,
     zoom: Option<f64>,
+    scale_limit: Option<ScaleLimit>,
     #[charming_skip_setter]
-    scale_limit: Option<(f64, f64)>,
-    #[charming_skip_setter]
-    name_map: Option<(String, String)>,
+    name_map: Option<BTreeMap<String, String>>,
     name_property: Option<String>,
     selected_mode: Option<bool>,
     label: Option<Label>,
@@ -56,13 +62,14 @@ impl Geo {
         self
     }
 
-    pub fn scale_limit<F: Into<f64>>(mut self, scale_limit: (F, F)) -> Self {
-        self.scale_limit = Some((scale_limit.0.into(), scale_limit.1.into()));
-        self
-    }
-
-    pub fn name_map<S: Into<String>>(mut self, name_map: (S, S)) -> Self {
-        self.name_map = Some((name_map.0.into(), name_map.1.into()));
+    /// Maps the names of the regions in the map data to custom names.
+    pub fn name_map<S: Into<String>>(mut self, name_map: Vec<(S, S)>) -> Self {
+        self.name_map = Some(
+            name_map
+                .into_iter()
+                .map(|(from, to)| (from.into(), to.into()))
+                .collect(),
+        );
         self
     }
 
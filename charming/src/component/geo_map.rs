@@ -1,3 +1,5 @@
+#[cfg(feature = "html")]
+use crate::element::escape_script_data;
 use charming_macros::CharmingSetters;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +14,24 @@ pub enum GeoMapOpt {
     Svg(String),
 }
 
+impl GeoMapOpt {
+    /// The `opt` argument of `echarts.registerMap`.
+    #[cfg(any(feature = "html", feature = "wasm"))]
+    pub(crate) fn to_register_map_opt(&self) -> serde_json::Value {
+        match self {
+            GeoMapOpt::GeoJson {
+                value,
+                special_areas,
+            } if special_areas.is_null() => serde_json::json!({ "geoJSON": value }),
+            GeoMapOpt::GeoJson {
+                value,
+                special_areas,
+            } => serde_json::json!({ "geoJSON": value, "specialAreas": special_areas }),
+            GeoMapOpt::Svg(svg) => serde_json::json!({ "svg": svg }),
+        }
+    }
+}
+
 impl<S> From<S> for GeoMapOpt
 where
     S: Into<String>,
@@ -32,6 +52,33 @@ pub struct GeoMap {
     opt: Option<GeoMapOpt>,
 }
 
+impl GeoMap {
+    /// The arguments of the `echarts.registerMap` call for this map, or `None`
+    /// if either the name or the map data is missing.
+    #[cfg(any(feature = "html", feature = "wasm"))]
+    pub(crate) fn register_map_args(&self) -> Option<(&str, serde_json::Value)> {
+        let name = self.name.as_deref()?;
+        let opt = self.opt.as_ref()?;
+        Some((name, opt.to_register_map_opt()))
+    }
+
+    /// The `echarts.registerMap` call for this map as a JavaScript statement.
+    #[cfg(feature = "html")]
+    pub(crate) fn register_map_script(&self) -> Option<String> {
+        let (name, opt) = self.register_map_args()?;
+        Some(escape_script_data(&format!(
+            "echarts.registerMap({}, {});",
+            serde_json::Value::from(name),
+            opt
+        )))
+    }
+
+    #[cfg(feature = "ssr")]
+    pub(crate) fn is_svg(&self) -> bool {
+        matches!(self.opt, Some(GeoMapOpt::Svg(_)))
+    }
+}
+
 impl From<&str> for GeoMap {
     fn from(svg: &str) -> Self {
         GeoMap::new().opt(GeoMapOpt::Svg(svg.to_string()))
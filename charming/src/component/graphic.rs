@@ -1 +1,188 @@
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        font_settings::{FontFamily, FontStyle, FontWeight},
+        Color, Cursor, JsFunction, TextAlign, TextVerticalAlign,
+    },
+};
+use charming_macros::CharmingSetters;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum GraphicType {
+    Group,
+    Image,
+    Text,
+    Rect,
+    Circle,
+    Ring,
+    Sector,
+    Arc,
+    Polygon,
+    Polyline,
+    Line,
+    BezierCurve,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphicAction {
+    /// Merge the element with the existing one of the same id.
+    Merge,
+    /// Replace the existing element of the same id.
+    Replace,
+    /// Remove the existing element of the same id.
+    Remove,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphicBounding {
+    /// The bounding box of the element and all of its children is used for
+    /// positioning.
+    All,
+    /// Only the element's own bounding box is used for positioning.
+    Raw,
+}
+
+/// Geometry of a graphic element. Which fields are meaningful depends on the
+/// [`GraphicType`] of the element.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphicShape {
+    x: Option<f64>,
+    y: Option<f64>,
+    width: Option<f64>,
+    height: Option<f64>,
+    /// Radius of a circle, ring, sector or arc, or the corner radius of a
+    /// rect.
+    r: Option<CompositeValue>,
+    /// Inner radius of a ring, sector or arc.
+    r0: Option<f64>,
+    cx: Option<f64>,
+    cy: Option<f64>,
+    start_angle: Option<f64>,
+    end_angle: Option<f64>,
+    clockwise: Option<bool>,
+    #[charming_skip_setter]
+    points: Vec<(f64, f64)>,
+    smooth: Option<f64>,
+    x1: Option<f64>,
+    y1: Option<f64>,
+    x2: Option<f64>,
+    y2: Option<f64>,
+    cpx1: Option<f64>,
+    cpy1: Option<f64>,
+    cpx2: Option<f64>,
+    cpy2: Option<f64>,
+    /// Proportion of a line or bezier curve to draw, from 0 to 1.
+    percent: Option<f64>,
+}
+
+impl GraphicShape {
+    pub fn points<F: Into<f64>>(mut self, points: Vec<(F, F)>) -> Self {
+        self.points = points
+            .into_iter()
+            .map(|(x, y)| (x.into(), y.into()))
+            .collect();
+        self
+    }
+}
+
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphicStyle {
+    fill: Option<Color>,
+    stroke: Option<Color>,
+    line_width: Option<f64>,
+    line_dash: Option<CompositeValue>,
+    opacity: Option<f64>,
+    shadow_blur: Option<f64>,
+    shadow_offset_x: Option<f64>,
+    shadow_offset_y: Option<f64>,
+    shadow_color: Option<Color>,
+    /// Image URL or data URI, for image elements.
+    image: Option<String>,
+    x: Option<f64>,
+    y: Option<f64>,
+    width: Option<f64>,
+    height: Option<f64>,
+    /// Text content, for text elements.
+    text: Option<String>,
+    /// Full CSS font declaration, e.g. `"bold 14px sans-serif"`.
+    font: Option<String>,
+    font_style: Option<FontStyle>,
+    font_weight: Option<FontWeight>,
+    font_family: Option<FontFamily>,
+    font_size: Option<f64>,
+    text_align: Option<TextAlign>,
+    text_vertical_align: Option<TextVerticalAlign>,
+}
+
+/// A graphic element, used to draw images, texts and shapes such as
+/// watermarks, logos or annotation boxes on top of a chart.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Graphic {
+    #[serde(rename = "type")]
+    type_: Option<GraphicType>,
+    /// Element ID, used to refer to the element when updating the chart.
+    id: Option<String>,
+    /// How to treat an existing element of the same id.
+    #[serde(rename = "$action")]
+    action: Option<GraphicAction>,
+    left: Option<CompositeValue>,
+    top: Option<CompositeValue>,
+    right: Option<CompositeValue>,
+    bottom: Option<CompositeValue>,
+    bounding: Option<GraphicBounding>,
+    /// Width of a group element.
+    width: Option<CompositeValue>,
+    /// Height of a group element.
+    height: Option<CompositeValue>,
+    x: Option<f64>,
+    y: Option<f64>,
+    rotation: Option<f64>,
+    scale_x: Option<f64>,
+    scale_y: Option<f64>,
+    origin_x: Option<f64>,
+    origin_y: Option<f64>,
+    /// The `zlevel` value of the element.
+    zlevel: Option<f64>,
+    /// The `z` value of the element.
+    z: Option<f64>,
+    silent: Option<bool>,
+    invisible: Option<bool>,
+    ignore: Option<bool>,
+    draggable: Option<bool>,
+    cursor: Option<Cursor>,
+    shape: Option<GraphicShape>,
+    style: Option<GraphicStyle>,
+    /// Text attached to the element.
+    text_content: Option<Box<Graphic>>,
+    /// Children of a group element.
+    #[charming_set_vec]
+    children: Vec<Graphic>,
+    onclick: Option<JsFunction>,
+    onmouseover: Option<JsFunction>,
+    onmouseout: Option<JsFunction>,
+    onmousemove: Option<JsFunction>,
+    onmousedown: Option<JsFunction>,
+    onmouseup: Option<JsFunction>,
+    ondrag: Option<JsFunction>,
+    ondragstart: Option<JsFunction>,
+    ondragend: Option<JsFunction>,
+}
@@ -1,6 +1,202 @@
+use crate::{
+    datatype::CompositeValue,
+    element::{AxisLabel, AxisLine, AxisTick, Color, Easing, SplitArea, SplitLine},
+};
 use charming_macros::CharmingSetters;
 use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Projection {
+    Perspective,
+    Orthographic,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoRotateDirection {
+    /// Clockwise, seen from above.
+    Cw,
+    /// Counter-clockwise, seen from above.
+    Ccw,
+}
+
+/// Camera settings of a 3D scene: projection, rotation, zooming and panning.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewControl {
+    projection: Option<Projection>,
+    auto_rotate: Option<bool>,
+    auto_rotate_direction: Option<AutoRotateDirection>,
+    /// Rotation speed, in degrees per second.
+    auto_rotate_speed: Option<f64>,
+    /// Seconds of inactivity after which auto rotation resumes.
+    auto_rotate_after_still: Option<f64>,
+    damping: Option<f64>,
+    rotate_sensitivity: Option<f64>,
+    zoom_sensitivity: Option<f64>,
+    pan_sensitivity: Option<f64>,
+    /// Distance from the camera to the center, for the perspective
+    /// projection.
+    distance: Option<f64>,
+    min_distance: Option<f64>,
+    max_distance: Option<f64>,
+    /// Size of the view, for the orthographic projection.
+    orthographic_size: Option<f64>,
+    min_orthographic_size: Option<f64>,
+    max_orthographic_size: Option<f64>,
+    /// Rotation around the x axis, in degrees.
+    alpha: Option<f64>,
+    /// Rotation around the y axis, in degrees.
+    beta: Option<f64>,
+    min_alpha: Option<f64>,
+    max_alpha: Option<f64>,
+    min_beta: Option<f64>,
+    max_beta: Option<f64>,
+    #[charming_skip_setter]
+    center: Option<(f64, f64, f64)>,
+    animation: Option<bool>,
+    animation_duration_update: Option<f64>,
+    animation_easing_update: Option<Easing>,
+}
+
+impl ViewControl {
+    pub fn center<F: Into<f64>>(mut self, center: (F, F, F)) -> Self {
+        self.center = Some((center.0.into(), center.1.into(), center.2.into()));
+        self
+    }
+}
+
+/// The main directional light of a 3D scene.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
 #[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Grid3D {}
+pub struct MainLight {
+    color: Option<Color>,
+    intensity: Option<f64>,
+    shadow: Option<bool>,
+    shadow_quality: Option<String>,
+    /// Angle of the light around the x axis, in degrees.
+    alpha: Option<f64>,
+    /// Angle of the light around the y axis, in degrees.
+    beta: Option<f64>,
+}
+
+/// The global ambient light of a 3D scene.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmbientLight {
+    color: Option<Color>,
+    intensity: Option<f64>,
+}
+
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Light {
+    main: Option<MainLight>,
+    ambient: Option<AmbientLight>,
+}
+
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Bloom {
+    enable: Option<bool>,
+    bloom_intensity: Option<f64>,
+}
+
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepthOfField {
+    enable: Option<bool>,
+    focal_distance: Option<f64>,
+    focal_range: Option<f64>,
+    f_stop: Option<f64>,
+    blur_radius: Option<f64>,
+}
+
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenSpaceAmbientOcclusion {
+    enable: Option<bool>,
+    quality: Option<String>,
+    radius: Option<f64>,
+    intensity: Option<f64>,
+}
+
+/// Post-processing effects applied to a rendered 3D scene.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostEffect {
+    enable: Option<bool>,
+    bloom: Option<Bloom>,
+    depth_of_field: Option<DepthOfField>,
+    #[serde(rename = "SSAO")]
+    ssao: Option<ScreenSpaceAmbientOcclusion>,
+}
+
+/// Grid3D is the 3D cartesian coordinate system, used by 3D series with the
+/// `cartesian3D` coordinate system. It needs the echarts-gl extension.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Grid3D {
+    show: Option<bool>,
+    /// Width of the grid in 3D space.
+    box_width: Option<f64>,
+    /// Height of the grid in 3D space.
+    box_height: Option<f64>,
+    /// Depth of the grid in 3D space.
+    box_depth: Option<f64>,
+    axis_line: Option<AxisLine>,
+    axis_label: Option<AxisLabel>,
+    axis_tick: Option<AxisTick>,
+    split_line: Option<SplitLine>,
+    split_area: Option<SplitArea>,
+    /// Background of the scene, either a color or an image URL.
+    environment: Option<String>,
+    light: Option<Light>,
+    post_effect: Option<PostEffect>,
+    view_control: Option<ViewControl>,
+    /// The `zlevel` value of the grid.
+    zlevel: Option<f64>,
+    left: Option<CompositeValue>,
+    top: Option<CompositeValue>,
+    right: Option<CompositeValue>,
+    bottom: Option<CompositeValue>,
+    width: Option<CompositeValue>,
+    height: Option<CompositeValue>,
+}
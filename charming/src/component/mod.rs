@@ -26,10 +26,12 @@ pub use angle_axis::*;
 pub use aria::*;
 pub use axis::*;
 pub use axis3d::*;
+pub use brush::*;
 pub use calendar::*;
 pub use data_zoom::*;
 pub use geo::*;
 pub use geo_map::*;
+pub use graphic::*;
 pub use grid::*;
 pub use grid3d::*;
 pub use legend::*;
@@ -39,6 +41,7 @@ pub use polar_coordinate::*;
 pub use radar_coordinate::*;
 pub use radius_axis::*;
 pub use single_axis::*;
+pub use timeline::*;
 pub use title::*;
 pub use toolbox::*;
 pub use visual_map::*;
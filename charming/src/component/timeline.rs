@@ -1 +1,118 @@
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        AxisType, Color, Easing, Emphasis, ItemStyle, Label, LineStyle, Orient, Padding, Symbol,
+    },
+};
+use charming_macros::CharmingSetters;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineType {
+    Slider,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineControlPosition {
+    Left,
+    Right,
+}
+
+/// Style of the checkpoint, i.e. the marker of the current frame.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineCheckpointStyle {
+    symbol: Option<Symbol>,
+    symbol_size: Option<f64>,
+    color: Option<Color>,
+    border_width: Option<f64>,
+    border_color: Option<Color>,
+    animation: Option<bool>,
+    animation_duration: Option<f64>,
+    animation_easing: Option<Easing>,
+}
+
+/// Style of the play, previous and next buttons.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineControlStyle {
+    show: Option<bool>,
+    show_play_btn: Option<bool>,
+    show_prev_btn: Option<bool>,
+    show_next_btn: Option<bool>,
+    item_size: Option<f64>,
+    item_gap: Option<f64>,
+    position: Option<TimelineControlPosition>,
+    play_icon: Option<String>,
+    stop_icon: Option<String>,
+    prev_icon: Option<String>,
+    next_icon: Option<String>,
+    color: Option<Color>,
+    border_color: Option<Color>,
+    border_width: Option<f64>,
+}
+
+/// Timeline component, which switches between the frames given in
+/// [`Chart::options`](crate::Chart::options). The timeline itself is placed in
+/// the [`Chart::base_option`](crate::Chart::base_option).
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Timeline {
+    #[serde(rename = "type")]
+    type_: Option<TimelineType>,
+    /// Whether to show the timeline component.
+    show: Option<bool>,
+    /// Type of the timeline axis.
+    axis_type: Option<AxisType>,
+    /// Index of the frame that is shown initially.
+    current_index: Option<f64>,
+    /// Whether to play automatically.
+    auto_play: Option<bool>,
+    /// Whether to play in the reverse direction.
+    rewind: Option<bool>,
+    /// Whether to loop playing.
+    #[serde(rename = "loop")]
+    loop_: Option<bool>,
+    /// Interval between two frames when playing, in milliseconds.
+    play_interval: Option<f64>,
+    /// Whether the view updates in real time while dragging the checkpoint.
+    realtime: Option<bool>,
+    /// Position of the control buttons.
+    control_position: Option<TimelineControlPosition>,
+    /// The `zlevel` value of all graphical elements in the timeline.
+    zlevel: Option<f64>,
+    /// The `z` value of all graphical elements in the timeline.
+    z: Option<f64>,
+    left: Option<CompositeValue>,
+    top: Option<CompositeValue>,
+    right: Option<CompositeValue>,
+    bottom: Option<CompositeValue>,
+    padding: Option<Padding>,
+    orient: Option<Orient>,
+    inverse: Option<bool>,
+    symbol: Option<Symbol>,
+    symbol_size: Option<f64>,
+    line_style: Option<LineStyle>,
+    label: Option<Label>,
+    item_style: Option<ItemStyle>,
+    checkpoint_style: Option<TimelineCheckpointStyle>,
+    control_style: Option<TimelineControlStyle>,
+    emphasis: Option<Emphasis>,
+    /// Labels of the frames.
+    #[charming_set_vec]
+    data: Vec<CompositeValue>,
+}
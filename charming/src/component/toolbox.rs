@@ -1,3 +1,4 @@
+use super::BrushType;
 use crate::{datatype::CompositeValue, element::Orient};
 use charming_macros::CharmingSetters;
 use serde::{Deserialize, Serialize};
@@ -80,24 +81,13 @@ pub struct MagicType {
     title: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
-#[serde(rename_all = "camelCase")]
-pub enum BrushType {
-    Rect,
-    Polygon,
-    LineX,
-    LineY,
-    Keep,
-    Clear,
-}
-
 #[serde_with::apply(
   Option => #[serde(skip_serializing_if = "Option::is_none")],
   Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
 )]
 #[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Brush {
+pub struct ToolboxBrush {
     #[serde(rename = "type")]
     #[charming_set_vec]
     type_: Vec<BrushType>,
@@ -125,7 +115,7 @@ pub struct Feature {
     data_view: Option<DataView>,
     magic_type: Option<MagicType>,
     data_zoom: Option<ToolboxDataZoom>,
-    brush: Option<Brush>,
+    brush: Option<ToolboxBrush>,
 }
 
 #[serde_with::apply(
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "snake_case")]
 pub enum CoordinateSystem {
     Cartesian2d,
+    #[serde(rename = "cartesian3D")]
+    Cartesian3d,
     Polar,
     Single,
     Geo,
@@ -42,6 +42,7 @@ pub mod range;
 pub mod sampling;
 pub mod scale_limit;
 pub mod select;
+pub mod series_layout_by;
 pub mod shape;
 pub mod smoothness;
 pub mod sort;
@@ -97,6 +98,7 @@ pub use range::*;
 pub use sampling::*;
 pub use scale_limit::*;
 pub use select::*;
+pub use series_layout_by::*;
 pub use shape::*;
 pub use sort::*;
 pub use split_area::*;
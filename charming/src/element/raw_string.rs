@@ -59,6 +59,31 @@ where
 }
 
 pub(crate) fn process_raw_strings(s: &str) -> String {
+    splice_raw_strings(s, |data| data.to_string())
+}
+
+/// Like [`process_raw_strings`], for output embedded in an HTML `<script>`
+/// element. The serialized data is escaped with [`escape_script_data`], so
+/// strings coming from users cannot close the element, while the bodies of
+/// raw strings, i.e. `JsFunction`s, are kept as they are.
+#[cfg(feature = "html")]
+pub(crate) fn process_raw_strings_for_script(s: &str) -> String {
+    splice_raw_strings(s, escape_script_data)
+}
+
+/// Escape serialized JSON for an HTML `<script>` element: `</` and `<!--`,
+/// which would end or confuse the element, and the line separators U+2028
+/// and U+2029. These only occur in JSON strings, where the escapes keep
+/// their value.
+#[cfg(feature = "html")]
+pub(crate) fn escape_script_data(data: &str) -> String {
+    data.replace("</", "<\\/")
+        .replace("<!--", "\\u003C!--")
+        .replace('\u{2028}', "\\u2028")
+        .replace('\u{2029}', "\\u2029")
+}
+
+fn splice_raw_strings(s: &str, data: impl Fn(&str) -> String) -> String {
     let left_mark = format!("\"{RAW_MARK}");
     let right_mark = format!("{RAW_MARK}\"");
 
@@ -67,7 +92,7 @@ pub(crate) fn process_raw_strings(s: &str) -> String {
 
     while pos < s.len() {
         let left = pos + s[pos..].find(&left_mark).unwrap_or_else(|| s.len() - pos);
-        output.push_str(&s[pos..left]);
+        output.push_str(&data(&s[pos..left]));
 
         if left >= s.len() {
             break;
@@ -125,4 +150,17 @@ mod test {
         let s = format!("foo\"{}b\\na\\nr{}\"baz", RAW_MARK, RAW_MARK);
         assert_eq!(process_raw_strings(&s), "foob\na\nrbaz");
     }
+
+    #[test]
+    #[cfg(feature = "html")]
+    fn raw_strings_for_script() {
+        let s = format!(
+            "[\"</script><!--\u{2028}\", \"{}function() {{ return '</b>'; }}{}\"]",
+            RAW_MARK, RAW_MARK
+        );
+        assert_eq!(
+            process_raw_strings_for_script(&s),
+            "[\"<\\/script>\\u003C!--\\u2028\", function() { return '</b>'; }]"
+        );
+    }
 }
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// How a series maps the rows and columns of a dataset to its dimensions.
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SeriesLayoutBy {
+    /// Each column of the dataset is a dimension.
+    Column,
+    /// Each row of the dataset is a dimension.
+    Row,
+}
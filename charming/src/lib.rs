@@ -25,6 +25,7 @@ provides three types of renderers:
   This renderer is disabled by default, and you need to enable the `ssr`
   (Server-Side Rendering) feature to use it.
   To render raster images like PNG the `ssr-raster` feature must also be enabled.
+  To render PDF documents the `ssr-pdf` feature must be enabled.
 - **WASM renderer**: `WasmRenderer` renders a chart in a WebAssembly runtime.
   This renderer is disabled by default, and you need to enable the `wasm`
   feature to use it. Note that the `wasm` feature and `ssr` feature are
@@ -82,22 +83,41 @@ ImageRenderer::new(1000, 800).theme(Theme::Westeros).save(
 );
 ```
 
-Future versions of Charming will support custom themes.
+Custom themes can be built at runtime with [`theme::ThemeDefinition`], or
+loaded from an ECharts theme JSON file:
+
+```rust
+use charming::{Chart, HtmlRenderer};
+use charming::theme::ThemeDefinition;
+use charming::component::Title;
+
+let theme = ThemeDefinition::from_json(
+    "brand",
+    r##"{ "color": ["#0b3c5d", "#328cc1"], "backgroundColor": "#fafafa" }"##,
+)
+.unwrap();
+HtmlRenderer::new("Brand", 1000, 800).theme(theme.into()).save(
+    &Chart::new().title(Title::new().text("Brand")),
+    "/tmp/brand.html",
+);
+```
  */
+pub mod action;
 pub mod component;
 pub mod datatype;
 pub mod element;
 pub mod renderer;
 pub mod series;
 pub mod theme;
+pub mod validate;
 
 pub use renderer::*;
 
 use charming_macros::CharmingSetters;
 use component::{
-    AngleAxis, Aria, Axis, Axis3D, Calendar, DataZoom, GeoMap, Grid, Grid3D, LegendConfig,
-    ParallelAxis, ParallelCoordinate, PolarCoordinate, RadarCoordinate, RadiusAxis,
-    SaveAsImageType, SingleAxis, Title, Toolbox, VisualMap,
+    AngleAxis, Aria, Axis, Axis3D, Brush, Calendar, DataZoom, Geo, GeoMap, Graphic, Grid, Grid3D,
+    LegendConfig, ParallelAxis, ParallelCoordinate, PolarCoordinate, RadarCoordinate, RadiusAxis,
+    SaveAsImageType, SingleAxis, Timeline, Title, Toolbox, VisualMap,
 };
 use datatype::Dataset;
 use element::{process_raw_strings, AnimationTime, AxisPointer, Color, Easing, MarkLine, Tooltip};
@@ -213,6 +233,12 @@ scatter and line charts. Every polar coordinate has an [`AngleAxis`] and a
 [`RadarCoordinate`] is the radar coordinate system. Radar coordinate can be in
 radar charts.
 
+### Geo
+
+[`Geo`] is the geographic coordinate system. The map it shows must be
+registered with [`Chart::geo_map`]. Geo can be used by scatter and lines
+series, and shared by map series through `geo_index`.
+
 ### Data Zoom
 
 [`DataZoom`] is used for zooming a specific area, which enables user to view
@@ -237,6 +263,41 @@ mouse pointer.
 
 [`Toolbox`] is a feature toolbox that includes data view, save as image, data
 zoom, restore, and reset.
+
+### Brush
+
+[`Brush`] is an area-selection component. Selected data items can be
+highlighted across linked series with `brush_link`.
+
+### Graphic
+
+[`Graphic`] elements are free-form images, texts and shapes drawn on top of
+the chart, e.g. watermarks, logos or annotation boxes.
+
+### Timeline
+
+[`Timeline`] switches between multiple frames of a chart. A chart with a
+timeline is composed of a base option, which holds the timeline and everything
+shared by all frames, and a list of per-frame partial options.
+
+```rust
+use charming::Chart;
+use charming::component::{Axis, Timeline};
+use charming::series::Bar;
+
+let chart = Chart::new()
+    .base_option(
+        Chart::new()
+            .timeline(Timeline::new().auto_play(true).data(vec!["2023", "2024"]))
+            .x_axis(Axis::new().data(vec!["Q1", "Q2", "Q3", "Q4"]))
+            .y_axis(Axis::new())
+            .series(Bar::new()),
+    )
+    .options(vec![
+        Chart::new().series(Bar::new().data(vec![1, 2, 3, 4])),
+        Chart::new().series(Bar::new().data(vec![4, 3, 2, 1])),
+    ]);
+```
  */
 #[serde_as]
 #[serde_with::apply(
@@ -258,6 +319,7 @@ pub struct Chart {
     tooltip: Option<Tooltip>,
     legend: Option<LegendConfig>,
     toolbox: Option<Toolbox>,
+    brush: Option<Brush>,
     grid: Vec<Grid>,
     #[serde(rename = "grid3D")]
     grid3d: Vec<Grid3D>,
@@ -281,6 +343,7 @@ pub struct Chart {
     data_zoom: Vec<DataZoom>,
     parallel: Option<ParallelCoordinate>,
     calendar: Option<Calendar>,
+    geo: Vec<Geo>,
     dataset: Option<Dataset>,
     radar: Vec<RadarCoordinate>,
     #[charming_set_vec]
@@ -288,9 +351,14 @@ pub struct Chart {
     background_color: Option<Color>,
     mark_line: Option<MarkLine>,
     aria: Option<Aria>,
+    graphic: Vec<Graphic>,
+    timeline: Option<Timeline>,
     series: Vec<Series>,
     #[serde(skip_serializing)]
     geo_map: Vec<GeoMap>,
+    base_option: Option<Box<Chart>>,
+    #[charming_set_vec]
+    options: Vec<Chart>,
 }
 impl Chart {
     pub fn save_as_image_type(&self) -> Option<&SaveAsImageType> {
@@ -298,6 +366,75 @@ impl Chart {
             .as_ref()
             .and_then(|toolbox| toolbox.save_as_image_type())
     }
+
+    /// The geo maps the renderers register with `echarts.registerMap`
+    /// before setting the option, including those of the timeline
+    /// `base_option` and `options` charts. Identical maps are returned once.
+    pub fn geo_maps(&self) -> Vec<&GeoMap> {
+        let mut geo_maps = vec![];
+        self.collect_geo_maps(&mut geo_maps);
+        geo_maps
+    }
+
+    fn collect_geo_maps<'a>(&'a self, geo_maps: &mut Vec<&'a GeoMap>) {
+        for geo_map in &self.geo_map {
+            if !geo_maps.contains(&geo_map) {
+                geo_maps.push(geo_map);
+            }
+        }
+        for chart in self.base_option.iter().map(|chart| &**chart) {
+            chart.collect_geo_maps(geo_maps);
+        }
+        for chart in &self.options {
+            chart.collect_geo_maps(geo_maps);
+        }
+    }
+
+    /// Check the chart for mistakes that ECharts only reports by rendering
+    /// nothing: references to components that do not exist, e.g. an
+    /// `x_axis_index`, `dataset_id` or `encode` dimension, series drawn on a
+    /// coordinate system they do not support, and series data not lining up
+    /// with the categories of their axes.
+    ///
+    /// ```rust
+    /// use charming::{Chart, component::Axis, series::Line, validate::Severity};
+    ///
+    /// let chart = Chart::new()
+    ///     .x_axis(Axis::new().data(vec!["Mon", "Tue", "Wed"]))
+    ///     .y_axis(Axis::new())
+    ///     .series(Line::new().x_axis_index(1).data(vec![1, 2, 3]));
+    ///
+    /// let diagnostics = chart.validate();
+    /// assert_eq!(diagnostics[0].path, "series[0].xAxisIndex");
+    /// assert_eq!(diagnostics[0].severity, Severity::Error);
+    /// ```
+    pub fn validate(&self) -> Vec<validate::Diagnostic> {
+        validate::validate(self)
+    }
+
+    /// Returns a copy of the chart whose timeline starts at the given frame,
+    /// or an error if the chart has no timeline or no such frame.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn with_timeline_frame(&self, frame: usize) -> Result<Chart, EchartsError> {
+        let mut chart = self.clone();
+        let timeline = match chart.base_option.as_mut() {
+            Some(base_option) => &mut base_option.timeline,
+            None => &mut chart.timeline,
+        };
+        let Some(current) = timeline.take() else {
+            return Err(EchartsError::ImageRenderingError(
+                "The chart has no timeline".to_string(),
+            ));
+        };
+        if frame >= self.options.len() {
+            return Err(EchartsError::ImageRenderingError(format!(
+                "Timeline frame {frame} is out of range, the chart has {} frames",
+                self.options.len()
+            )));
+        }
+        *timeline = Some(current.current_index(frame as f64));
+        Ok(chart)
+    }
 }
 
 impl std::fmt::Display for Chart {
@@ -310,12 +447,71 @@ impl std::fmt::Display for Chart {
     }
 }
 
+/// An exception thrown while rendering a chart in the JavaScript runtime.
+#[derive(Debug, PartialEq, Clone)]
+pub struct JsException {
+    /// The exception message, e.g. `TypeError: params.value is undefined`
+    pub message: String,
+    /// The JavaScript stack trace, if any
+    pub stack: Option<String>,
+    /// Path of the option whose `JsFunction` threw the exception, e.g.
+    /// `series[0].label.formatter`, if the exception was thrown by one
+    pub option_path: Option<String>,
+    /// The console messages logged before the exception was thrown
+    pub console: Vec<ConsoleMessage>,
+}
+
+impl std::fmt::Display for JsException {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(option_path) = &self.option_path {
+            write!(f, " (in option `{option_path}`)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Level of a [`ConsoleMessage`], after the `console` method which logged it
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsoleLevel {
+    Debug,
+    Log,
+    Info,
+    Warn,
+    Error,
+}
+
+/// A message logged to the console by ECharts or a `JsFunction` of the chart
+/// while rendering it with the `ImageRenderer`
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
+pub struct ConsoleMessage {
+    pub level: ConsoleLevel,
+    pub message: String,
+}
+
 #[derive(Debug)]
 pub enum EchartsError {
     HtmlRenderingError(String),
     ImageRenderingError(String),
     JsRuntimeError(String),
     WasmError(String),
+    ThemeLoadingError(String),
+    /// The chart threw an exception in the JavaScript runtime.
+    JsException(JsException),
+    /// Rendering took longer than the execution timeout.
+    Timeout {
+        timeout: std::time::Duration,
+        /// The console messages logged before the render was aborted
+        console: Vec<ConsoleMessage>,
+    },
+    /// Rendering used more memory than the heap limit.
+    HeapLimitExceeded {
+        /// The heap limit, in bytes
+        limit: usize,
+        /// The console messages logged before the render was aborted
+        console: Vec<ConsoleMessage>,
+    },
 }
 
 impl std::error::Error for EchartsError {}
@@ -326,6 +522,12 @@ impl std::fmt::Display for EchartsError {
             Self::ImageRenderingError(msg) => write!(f, "Image rendering error: {msg}"),
             Self::JsRuntimeError(msg) => write!(f, "JavaScript runtime error: {msg}"),
             Self::WasmError(msg) => write!(f, "WebAssembly runtime error: {msg}"),
+            Self::ThemeLoadingError(msg) => write!(f, "Theme loading error: {msg}"),
+            Self::JsException(exception) => write!(f, "JavaScript exception: {exception}"),
+            Self::Timeout { timeout, .. } => write!(f, "Rendering timed out after {timeout:?}"),
+            Self::HeapLimitExceeded { limit, .. } => {
+                write!(f, "Rendering exceeded the heap limit of {limit} bytes")
+            }
         }
     }
 }
@@ -1,12 +1,566 @@
+use std::{
+    borrow::Cow,
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+};
+
 use handlebars::Handlebars;
+use serde::Serialize;
+
+use crate::{
+    component::SaveAsImageType,
+    element::{escape_script_data, process_raw_strings_for_script, JsFunction},
+    theme::Theme,
+    Chart, EchartsError,
+};
+
+/// Format of a font embedded with [`FontFace::from_data`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FontFormat {
+    Woff2,
+    Woff,
+    TrueType,
+    OpenType,
+}
+
+impl FontFormat {
+    fn css_format(&self) -> &'static str {
+        match self {
+            FontFormat::Woff2 => "woff2",
+            FontFormat::Woff => "woff",
+            FontFormat::TrueType => "truetype",
+            FontFormat::OpenType => "opentype",
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match self {
+            FontFormat::Woff2 => "font/woff2",
+            FontFormat::Woff => "font/woff",
+            FontFormat::TrueType => "font/ttf",
+            FontFormat::OpenType => "font/otf",
+        }
+    }
+}
+
+/// A CSS `@font-face` declaration, making a font available to the
+/// `FontFamily` values of the chart.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FontFace {
+    family: String,
+    src: String,
+    format: Option<FontFormat>,
+    weight: Option<String>,
+    style: Option<String>,
+}
+
+impl FontFace {
+    /// A font loaded from a URL
+    pub fn new<F: Into<String>, S: Into<String>>(family: F, url: S) -> Self {
+        Self {
+            family: family.into(),
+            src: url.into(),
+            format: None,
+            weight: None,
+            style: None,
+        }
+    }
+
+    /// A font embedded in the page as a data URL
+    pub fn from_data<F: Into<String>>(family: F, data: &[u8], format: FontFormat) -> Self {
+        Self {
+            family: family.into(),
+            src: format!("data:{};base64,{}", format.mime_type(), base64(data)),
+            format: Some(format),
+            weight: None,
+            style: None,
+        }
+    }
+
+    pub fn format(mut self, format: FontFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// The `font-weight` descriptor, e.g. `"bold"` or `"100 900"`
+    pub fn weight<S: Into<String>>(mut self, weight: S) -> Self {
+        self.weight = Some(weight.into());
+        self
+    }
+
+    /// The `font-style` descriptor, e.g. `"italic"`
+    pub fn style<S: Into<String>>(mut self, style: S) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    fn to_css(&self) -> String {
+        let mut css = format!(
+            "@font-face {{ font-family: {}; src: url({})",
+            css_string(&self.family),
+            css_string(&self.src)
+        );
+        if let Some(format) = &self.format {
+            css.push_str(&format!(" format(\"{}\")", format.css_format()));
+        }
+        if let Some(weight) = &self.weight {
+            css.push_str(&format!("; font-weight: {}", css_value(weight)));
+        }
+        if let Some(style) = &self.style {
+            css.push_str(&format!("; font-style: {}", css_value(style)));
+        }
+        css.push_str("; }");
+        css
+    }
+}
+
+/// Quote a CSS string, escaping anything that could end the string or the
+/// `<style>` element
+fn css_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' | '<' | '>' | '\n' | '\r' => {
+                quoted.push_str(&format!("\\{:x} ", c as u32));
+            }
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Keep the characters of a CSS descriptor value that cannot break out of it
+fn css_value(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '.' | '%'))
+        .collect()
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// A chart of a [`Dashboard`] and its place in the grid.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Panel {
+    chart: Chart,
+    id: Option<String>,
+    column: Option<usize>,
+    row: Option<usize>,
+    column_span: usize,
+    row_span: usize,
+    group: Option<String>,
+    event_handlers: Vec<EventHandler>,
+}
+
+impl Panel {
+    pub fn new(chart: Chart) -> Self {
+        Self {
+            chart,
+            id: None,
+            column: None,
+            row: None,
+            column_span: 1,
+            row_span: 1,
+            group: None,
+            event_handlers: vec![],
+        }
+    }
+
+    /// The id of the panel element, `chart-<index>` by default. Ids may only
+    /// contain ASCII letters, digits, `-` and `_`.
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// The 1-based grid column the panel starts at, the next free cell by
+    /// default
+    pub fn column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// The 1-based grid row the panel starts at, the next free cell by
+    /// default
+    pub fn row(mut self, row: usize) -> Self {
+        self.row = Some(row);
+        self
+    }
+
+    pub fn column_span(mut self, column_span: usize) -> Self {
+        self.column_span = column_span.max(1);
+        self
+    }
+
+    pub fn row_span(mut self, row_span: usize) -> Self {
+        self.row_span = row_span.max(1);
+        self
+    }
+
+    /// Connect the panel to the other panels of the same group with
+    /// `echarts.connect`, which synchronizes their tooltips, data zoom and
+    /// legend selection.
+    pub fn group<S: Into<String>>(mut self, group: S) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Handle an event of this panel's chart, like [`HtmlRenderer::on`]
+    /// does for every chart of the dashboard
+    pub fn on<E: Into<String>>(mut self, event: E, handler: JsFunction) -> Self {
+        self.event_handlers
+            .push(EventHandler::new(event.into(), None, handler));
+        self
+    }
+
+    /// Handle an event of the components of this panel's chart matching
+    /// `query` only, like [`HtmlRenderer::on_query`]
+    pub fn on_query<E, Q>(mut self, event: E, query: Q, handler: JsFunction) -> Self
+    where
+        E: Into<String>,
+        Q: Into<serde_json::Value>,
+    {
+        self.event_handlers
+            .push(EventHandler::new(event.into(), Some(query.into()), handler));
+        self
+    }
+
+    fn style(&self) -> String {
+        let mut style = vec![];
+        match self.column {
+            Some(column) => style.push(format!(
+                "grid-column: {} / span {}",
+                column, self.column_span
+            )),
+            None if self.column_span > 1 => {
+                style.push(format!("grid-column: span {}", self.column_span))
+            }
+            None => {}
+        }
+        match self.row {
+            Some(row) => style.push(format!("grid-row: {} / span {}", row, self.row_span)),
+            None if self.row_span > 1 => style.push(format!("grid-row: span {}", self.row_span)),
+            None => {}
+        }
+        style.join("; ")
+    }
+}
+
+impl From<Chart> for Panel {
+    fn from(chart: Chart) -> Self {
+        Self::new(chart)
+    }
+}
+
+/// Many charts laid out in a CSS grid, rendered by
+/// [`HtmlRenderer::render_dashboard`].
+///
+/// ```rust
+/// use charming::{Chart, Dashboard, HtmlRenderer, Panel};
+///
+/// let dashboard = Dashboard::new(2)
+///     .row_height(300)
+///     .panel(Panel::new(Chart::new()).column_span(2).group("traffic"))
+///     .panel(Panel::new(Chart::new()).group("traffic"))
+///     .panel(Chart::new());
+///
+/// let html = HtmlRenderer::new("Monitoring", 1200, 300)
+///     .render_dashboard(&dashboard)
+///     .unwrap();
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Dashboard {
+    columns: usize,
+    row_height: Option<u64>,
+    gap: u64,
+    panels: Vec<Panel>,
+}
+
+impl Dashboard {
+    /// A dashboard whose grid has `columns` columns of equal width
+    pub fn new(columns: usize) -> Self {
+        Self {
+            columns: columns.max(1),
+            row_height: None,
+            gap: 16,
+            panels: vec![],
+        }
+    }
+
+    /// Height of a grid row in pixels, the height of the renderer by default,
+    /// or 400 if it is relative
+    pub fn row_height(mut self, row_height: u64) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Space between the panels in pixels, 16 by default
+    pub fn gap(mut self, gap: u64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn panel<P: Into<Panel>>(mut self, panel: P) -> Self {
+        self.panels.push(panel.into());
+        self
+    }
+}
+
+const DEFAULT_ROW_HEIGHT: u64 = 400;
+
+/// Whether `id` can be used as is in an HTML attribute and a JavaScript
+/// string
+fn valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+}
+
+/// A JavaScript string literal that cannot close the `<script>` element
+fn js_string(value: &str) -> String {
+    escape_script_data(&serde_json::Value::from(value).to_string())
+}
+
+/// The option of a chart, as JavaScript that is safe to embed in a
+/// `<script>` element
+fn chart_option(chart: &Chart) -> Result<String, EchartsError> {
+    let json = serde_json::to_string_pretty(chart)
+        .map_err(|error| EchartsError::HtmlRenderingError(error.to_string()))?;
+    Ok(process_raw_strings_for_script(&json))
+}
+
+fn canvas_type(chart: &Chart) -> &'static str {
+    match chart.save_as_image_type() {
+        Some(&SaveAsImageType::Svg) => "svg",
+        _ => "canvas",
+    }
+}
+
+/// Width or height of a chart element
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Size {
+    Pixels(u64),
+    /// A percentage of the parent element. Charts with a relative size are
+    /// resized along with the browser window.
+    Percent(f64),
+}
+
+impl Size {
+    fn to_css(self) -> String {
+        match self {
+            Size::Pixels(pixels) => format!("{pixels}px"),
+            Size::Percent(percent) => format!("{percent}%"),
+        }
+    }
+
+    fn is_relative(self) -> bool {
+        matches!(self, Size::Percent(_))
+    }
+}
+
+impl From<u64> for Size {
+    fn from(pixels: u64) -> Self {
+        Size::Pixels(pixels)
+    }
+}
+
+/// Generate an element id that is unique within the page, even when the
+/// page combines fragments rendered by different processes.
+fn unique_id() -> String {
+    static PREFIX: OnceLock<u32> = OnceLock::new();
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let prefix = PREFIX.get_or_init(|| RandomState::new().hash_one(std::process::id()) as u32);
+    format!(
+        "charming-{prefix:08x}-{}",
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// File name of the bundled ECharts script loaded by the pages of
+/// [`HtmlRenderer::render_external`]
+pub const ECHARTS_SCRIPT_NAME: &str = "echarts.min.js";
+
+/// A chart page loading all its scripts from files, for pages served under a
+/// `Content-Security-Policy` of `script-src 'self'`. Rendered by
+/// [`HtmlRenderer::render_external`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExternalPage {
+    /// The HTML page
+    pub html: String,
+    /// The script initializing the chart, to serve at the `script_src` the
+    /// page was rendered with
+    pub script: String,
+    /// The bundled ECharts script, to serve at [`ECHARTS_SCRIPT_NAME`] next
+    /// to the page
+    pub echarts: &'static str,
+}
+
+/// A chart event handler, see [`HtmlRenderer::on`]
+#[derive(Debug, PartialEq, Clone)]
+struct EventHandler {
+    event: String,
+    query: Option<serde_json::Value>,
+    handler: JsFunction,
+}
+
+impl EventHandler {
+    fn new(event: String, query: Option<serde_json::Value>, handler: JsFunction) -> Self {
+        Self {
+            event,
+            query,
+            handler,
+        }
+    }
+
+    /// The `chart.on` call registering the handler
+    fn to_script(&self) -> Result<String, EchartsError> {
+        let handler = serde_json::to_string(&self.handler)
+            .map_err(|error| EchartsError::HtmlRenderingError(error.to_string()))?;
+        let mut args = vec![js_string(&self.event)];
+        if let Some(query) = &self.query {
+            args.push(escape_script_data(&query.to_string()));
+        }
+        args.push(process_raw_strings_for_script(&handler));
+        Ok(format!("chart.on({});", args.join(", ")))
+    }
+}
+
+fn event_handler_scripts(event_handlers: &[EventHandler]) -> Result<Vec<String>, EchartsError> {
+    event_handlers.iter().map(EventHandler::to_script).collect()
+}
 
-use crate::{component::SaveAsImageType, theme::Theme, Chart, EchartsError};
+/// The `echarts.registerMap` calls of the maps used by `charts`, without
+/// duplicates
+fn geo_map_scripts<'a>(charts: impl IntoIterator<Item = &'a Chart>) -> Vec<String> {
+    let mut scripts = vec![];
+    for script in charts
+        .into_iter()
+        .flat_map(|chart| chart.geo_maps())
+        .filter_map(|geo_map| geo_map.register_map_script())
+    {
+        if !scripts.contains(&script) {
+            scripts.push(script);
+        }
+    }
+    scripts
+}
+
+/// Template data shared by pages, fragments and dashboards
+#[derive(Serialize)]
+struct PageData<'a> {
+    title: &'a str,
+    theme: Option<String>,
+    theme_source: Cow<'static, str>,
+    width: String,
+    height: String,
+    responsive: bool,
+    nonce: Option<&'a str>,
+    echarts_scripts: Option<String>,
+    font_faces: Vec<String>,
+    geo_maps: Vec<String>,
+    event_handlers: Vec<String>,
+}
+
+/// Template data of a chart element
+#[derive(Serialize)]
+struct ChartData {
+    chart_id: String,
+    canvas_type: &'static str,
+    chart_option: String,
+}
+
+impl ChartData {
+    fn new(chart_id: String, chart: &Chart) -> Result<Self, EchartsError> {
+        Ok(Self {
+            chart_id,
+            canvas_type: canvas_type(chart),
+            chart_option: chart_option(chart)?,
+        })
+    }
+}
+
+/// Template data of a single chart page or fragment
+#[derive(Serialize)]
+struct ChartPageData<'a> {
+    #[serde(flatten)]
+    page: PageData<'a>,
+    #[serde(flatten)]
+    chart: ChartData,
+    chart_script_src: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct PanelData {
+    #[serde(flatten)]
+    chart: ChartData,
+    style: String,
+    group: Option<String>,
+    event_handlers: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct DashboardData<'a> {
+    #[serde(flatten)]
+    page: PageData<'a>,
+    columns: usize,
+    row_height: u64,
+    gap: u64,
+    panels: Vec<PanelData>,
+    groups: Vec<String>,
+}
+
+fn handlebars() -> Result<Handlebars<'static>, EchartsError> {
+    let mut handlebars = Handlebars::new();
+    // Indenting the partial would change multi-line strings of the option.
+    handlebars.set_prevent_indent(true);
+    handlebars
+        .register_partial("chart_script", include_str!("../asset/chart_script.js.hbs"))
+        .map_err(|error| EchartsError::HtmlRenderingError(error.to_string()))?;
+    Ok(handlebars)
+}
+
+fn render_template<T: Serialize>(template: &str, data: &T) -> Result<String, EchartsError> {
+    handlebars()?
+        .render_template(template, data)
+        .map_err(|error| EchartsError::HtmlRenderingError(error.to_string()))
+}
 
 pub struct HtmlRenderer {
     title: String,
     theme: Theme,
-    width: u64,
-    height: u64,
+    width: Size,
+    height: Size,
+    offline: bool,
+    include_echarts: bool,
+    nonce: Option<String>,
+    font_faces: Vec<FontFace>,
+    event_handlers: Vec<EventHandler>,
 }
 
 impl HtmlRenderer {
@@ -14,8 +568,13 @@ impl HtmlRenderer {
         Self {
             title: title.into(),
             theme: Theme::Default,
-            width,
-            height,
+            width: Size::Pixels(width),
+            height: Size::Pixels(height),
+            offline: false,
+            include_echarts: true,
+            nonce: None,
+            font_faces: vec![],
+            event_handlers: vec![],
         }
     }
 
@@ -24,29 +583,308 @@ impl HtmlRenderer {
         self
     }
 
+    /// Inline the bundled ECharts script instead of loading it from a CDN, so
+    /// the page works without network access. The echarts-gl extension is
+    /// not bundled, so 3D charts still need it to be loaded separately.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Width of the chart, e.g. `Size::Percent(100.0)` to fill its parent
+    pub fn width<S: Into<Size>>(mut self, width: S) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Height of the chart. A percentage height needs a parent element with a
+    /// height.
+    pub fn height<S: Into<Size>>(mut self, height: S) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Whether [`Self::render_fragment`] loads ECharts, `true` by default.
+    /// Turn it off when the page already includes ECharts, e.g. with
+    /// [`Self::script_tags`].
+    pub fn include_echarts(mut self, include_echarts: bool) -> Self {
+        self.include_echarts = include_echarts;
+        self
+    }
+
+    /// Add a `nonce` attribute to the `<script>` and `<style>` elements, so
+    /// they are allowed by a `Content-Security-Policy` of
+    /// `script-src 'nonce-<nonce>'`. The nonce must be generated anew for
+    /// every response.
+    pub fn nonce<S: Into<String>>(mut self, nonce: S) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    /// Declare a font with `@font-face`, so it can be used by the chart
+    pub fn font_face(mut self, font_face: FontFace) -> Self {
+        self.font_faces.push(font_face);
+        self
+    }
+
+    /// Handle a chart event, e.g. `"click"`, `"legendselectchanged"` or
+    /// `"datazoom"`, with `chart.on(event, handler)`. The handler is called
+    /// with the event params, and `this` is the chart. The handlers of the
+    /// renderer are attached to every chart of a dashboard, use [`Panel::on`]
+    /// to handle the events of one panel.
+    ///
+    /// ```rust
+    /// use charming::{element::JsFunction, HtmlRenderer};
+    ///
+    /// let renderer = HtmlRenderer::new("sales", 800, 600).on(
+    ///     "click",
+    ///     JsFunction::new_with_args(
+    ///         "params",
+    ///         "window.location.href = '/sales/' + encodeURIComponent(params.name);",
+    ///     ),
+    /// );
+    /// ```
+    pub fn on<E: Into<String>>(mut self, event: E, handler: JsFunction) -> Self {
+        self.event_handlers
+            .push(EventHandler::new(event.into(), None, handler));
+        self
+    }
+
+    /// Handle a chart event of the components matching `query` only, with
+    /// `chart.on(event, query, handler)`. The query is either a string like
+    /// `"series.bar"`, or an object like `json!({ "seriesIndex": 1 })`.
+    pub fn on_query<E, Q>(mut self, event: E, query: Q, handler: JsFunction) -> Self
+    where
+        E: Into<String>,
+        Q: Into<serde_json::Value>,
+    {
+        self.event_handlers
+            .push(EventHandler::new(event.into(), Some(query.into()), handler));
+        self
+    }
+
     pub fn render(&self, chart: &Chart) -> Result<String, EchartsError> {
-        let template = include_str!("../asset/charts.html.hbs");
-        let (theme, theme_source) = self.theme.to_str();
-        let canvas_type = match chart.save_as_image_type() {
-            Some(&SaveAsImageType::Svg) => "svg".to_string(),
-            _ => "canvas".to_string(),
+        let data = self.chart_page_data("chart", chart, None, Some(self.script_tags()))?;
+        render_template(include_str!("../asset/charts.html.hbs"), &data)
+    }
+
+    /// Render a chart to a page without inline scripts: the page loads the
+    /// bundled ECharts from [`ECHARTS_SCRIPT_NAME`] and the chart from
+    /// `script_src`, both relative to the page. Option values like
+    /// `JsFunction`s end up in the external script, so the page works under
+    /// a `Content-Security-Policy` of `script-src 'self'`. The echarts-gl
+    /// extension is not bundled, so 3D charts are not supported.
+    ///
+    /// ```rust
+    /// use charming::{Chart, HtmlRenderer};
+    ///
+    /// let page = HtmlRenderer::new("my chart", 800, 600)
+    ///     .render_external(&Chart::new(), "chart.js")
+    ///     .unwrap();
+    /// assert!(page.html.contains(r#"<script src="chart.js"></script>"#));
+    /// assert!(page.script.contains("echarts.init("));
+    /// ```
+    pub fn render_external(
+        &self,
+        chart: &Chart,
+        script_src: &str,
+    ) -> Result<ExternalPage, EchartsError> {
+        let echarts_scripts = format!(
+            "<script src=\"{ECHARTS_SCRIPT_NAME}\"{}></script>",
+            self.nonce_attribute()
+        );
+        let data = self.chart_page_data("chart", chart, Some(script_src), Some(echarts_scripts))?;
+        Ok(ExternalPage {
+            html: render_template(include_str!("../asset/charts.html.hbs"), &data)?,
+            script: render_template(include_str!("../asset/chart_script.js.hbs"), &data)?,
+            echarts: include_str!("../asset/echarts-5.5.1.min.js"),
+        })
+    }
+
+    /// Save a chart page rendered with [`Self::render_external`] to `path`,
+    /// along with its script, named after the page, and the bundled ECharts
+    /// script, e.g. `chart.html`, `chart.js` and `echarts.min.js`.
+    pub fn save_external<P: AsRef<std::path::Path>>(
+        &self,
+        chart: &Chart,
+        path: P,
+    ) -> Result<(), EchartsError> {
+        let path = path.as_ref();
+        let script_path = path.with_extension("js");
+        let script_src = script_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| {
+                EchartsError::HtmlRenderingError(format!("invalid page path {}", path.display()))
+            })?;
+        let page = self.render_external(chart, script_src)?;
+        let write = |path: &std::path::Path, contents: &str| {
+            std::fs::write(path, contents)
+                .map_err(|error| EchartsError::HtmlRenderingError(error.to_string()))
         };
-        let data = Handlebars::new()
-            .render_template(
-                template,
-                &serde_json::json!({
-                    "title": self.title,
-                    "theme": theme,
-                    "theme_source": theme_source,
-                    "width": self.width,
-                    "height": self.height,
-                    "chart_id": "chart",
-                    "canvas_type": canvas_type,
-                    "chart_option": chart.to_string(),
-                }),
+        write(path, &page.html)?;
+        write(&script_path, &page.script)?;
+        write(&path.with_file_name(ECHARTS_SCRIPT_NAME), page.echarts)
+    }
+
+    fn page_data(
+        &self,
+        echarts_scripts: Option<String>,
+        geo_maps: Vec<String>,
+    ) -> Result<PageData<'_>, EchartsError> {
+        Ok(PageData {
+            title: &self.title,
+            theme: self.theme.name_literal(),
+            theme_source: self.theme.source()?,
+            width: self.width.to_css(),
+            height: self.height.to_css(),
+            responsive: self.is_responsive(),
+            nonce: self.nonce.as_deref(),
+            echarts_scripts,
+            font_faces: self.font_faces_css(),
+            geo_maps,
+            event_handlers: event_handler_scripts(&self.event_handlers)?,
+        })
+    }
+
+    fn chart_page_data<'a>(
+        &'a self,
+        chart_id: &str,
+        chart: &Chart,
+        chart_script_src: Option<&'a str>,
+        echarts_scripts: Option<String>,
+    ) -> Result<ChartPageData<'a>, EchartsError> {
+        Ok(ChartPageData {
+            page: self.page_data(echarts_scripts, geo_map_scripts([chart]))?,
+            chart: ChartData::new(chart_id.to_string(), chart)?,
+            chart_script_src,
+        })
+    }
+
+    /// Render a dashboard of many charts to a full HTML page.
+    pub fn render_dashboard(&self, dashboard: &Dashboard) -> Result<String, EchartsError> {
+        let mut ids = std::collections::BTreeSet::new();
+        let mut groups = vec![];
+        let mut panels = vec![];
+        for (i, panel) in dashboard.panels.iter().enumerate() {
+            let id = panel.id.clone().unwrap_or_else(|| format!("chart-{i}"));
+            if !valid_id(&id) {
+                return Err(EchartsError::HtmlRenderingError(format!(
+                    "invalid panel id \"{id}\", ids may only contain ASCII letters, digits, '-' and '_'"
+                )));
+            }
+            if !ids.insert(id.clone()) {
+                return Err(EchartsError::HtmlRenderingError(format!(
+                    "duplicate panel id \"{id}\""
+                )));
+            }
+            let group = panel.group.as_deref().map(js_string);
+            if let Some(group) = &group {
+                if !groups.contains(group) {
+                    groups.push(group.clone());
+                }
+            }
+            panels.push(PanelData {
+                chart: ChartData::new(id, &panel.chart)?,
+                style: panel.style(),
+                group,
+                event_handlers: event_handler_scripts(&panel.event_handlers)?,
+            });
+        }
+
+        let geo_maps = geo_map_scripts(dashboard.panels.iter().map(|panel| &panel.chart));
+        let data = DashboardData {
+            page: self.page_data(Some(self.script_tags()), geo_maps)?,
+            columns: dashboard.columns,
+            row_height: dashboard.row_height.unwrap_or(match self.height {
+                Size::Pixels(height) => height,
+                Size::Percent(_) => DEFAULT_ROW_HEIGHT,
+            }),
+            gap: dashboard.gap,
+            panels,
+            groups,
+        };
+        render_template(include_str!("../asset/dashboard.html.hbs"), &data)
+    }
+
+    pub fn save_dashboard<P: AsRef<std::path::Path>>(
+        &self,
+        dashboard: &Dashboard,
+        path: P,
+    ) -> Result<(), EchartsError> {
+        let html = self.render_dashboard(dashboard)?;
+        std::fs::write(path, html)
+            .map_err(|error| EchartsError::HtmlRenderingError(error.to_string()))
+    }
+
+    /// Render a chart to an HTML fragment, a `<div>` and the `<script>`
+    /// initializing it, to embed in a page rendered by a template engine.
+    /// The `<div>` gets a generated id that is unique within the page.
+    ///
+    /// ```rust
+    /// use charming::{Chart, HtmlRenderer, Size};
+    ///
+    /// let renderer = HtmlRenderer::new("", 800, 400)
+    ///     .width(Size::Percent(100.0))
+    ///     .include_echarts(false);
+    ///
+    /// // In the head of the page, once
+    /// let scripts = renderer.script_tags();
+    /// // In the body, once per chart
+    /// let sales = renderer.render_fragment(&Chart::new()).unwrap();
+    /// let costs = renderer.render_fragment_with_id("costs", &Chart::new()).unwrap();
+    /// ```
+    pub fn render_fragment(&self, chart: &Chart) -> Result<String, EchartsError> {
+        self.render_fragment_with_id(&unique_id(), chart)
+    }
+
+    /// Render a chart to an HTML fragment like [`Self::render_fragment`],
+    /// with the given `<div>` id. Ids may only contain ASCII letters, digits,
+    /// `-` and `_`.
+    pub fn render_fragment_with_id(&self, id: &str, chart: &Chart) -> Result<String, EchartsError> {
+        if !valid_id(id) {
+            return Err(EchartsError::HtmlRenderingError(format!(
+                "invalid chart id \"{id}\", ids may only contain ASCII letters, digits, '-' and '_'"
+            )));
+        }
+        let echarts_scripts = self.include_echarts.then(|| self.script_tags());
+        let data = self.chart_page_data(id, chart, None, echarts_scripts)?;
+        render_template(include_str!("../asset/fragment.html.hbs"), &data)
+    }
+
+    /// The `<script>` tags loading ECharts, from the CDN or inline if the
+    /// renderer is [offline](Self::offline), for pages embedding fragments.
+    pub fn script_tags(&self) -> String {
+        let nonce = self.nonce_attribute();
+        if self.offline {
+            format!(
+                "<script type=\"text/javascript\"{nonce}>{}</script>",
+                include_str!("../asset/echarts-5.5.1.min.js")
             )
-            .map_err(|error| EchartsError::HtmlRenderingError(error.to_string()))?;
-        Ok(data)
+        } else {
+            format!(
+                "<script src=\"https://cdn.jsdelivr.net/npm/echarts@5.5.1/dist/echarts.min.js\"{nonce}></script>\n\
+                 <script src=\"https://cdn.jsdelivr.net/npm/echarts-gl@2.0.9/dist/echarts-gl.min.js\"{nonce}></script>"
+            )
+        }
+    }
+
+    fn nonce_attribute(&self) -> String {
+        match &self.nonce {
+            Some(nonce) => format!(" nonce=\"{}\"", handlebars::html_escape(nonce)),
+            None => String::new(),
+        }
+    }
+
+    fn is_responsive(&self) -> bool {
+        self.width.is_relative() || self.height.is_relative()
+    }
+
+    fn font_faces_css(&self) -> Vec<String> {
+        self.font_faces
+            .iter()
+            .map(|font_face| font_face.to_css())
+            .collect()
     }
 
     pub fn save<P: AsRef<std::path::Path>>(
@@ -1,60 +1,126 @@
-use deno_core::{v8, JsRuntime, RuntimeOptions};
+use deno_core::{error::JsError, v8, JsRuntime, RuntimeOptions};
 use handlebars::Handlebars;
+use std::{cell::Cell, rc::Rc, sync::mpsc, time::Duration};
 
 #[cfg(feature = "ssr-raster")]
 use image::RgbaImage;
 #[cfg(feature = "ssr-raster")]
 use resvg::{tiny_skia::Pixmap, usvg};
 #[cfg(feature = "ssr-raster")]
+use std::collections::BTreeMap;
+#[cfg(feature = "ssr-pdf")]
+use std::collections::HashMap;
+#[cfg(feature = "ssr-raster")]
 use std::io::Cursor;
 #[cfg(feature = "ssr-raster")]
 use std::sync::Arc;
 
-use crate::{theme::Theme, Chart, EchartsError};
+use super::option_path::option_path_at;
+use crate::{theme::Theme, Chart, ConsoleLevel, ConsoleMessage, EchartsError, JsException};
 
 static CODE_TEMPLATE: &str = r#"
 {{#if theme_source}}{{{ theme_source }}}{{/if}}
-var chart = echarts.init(null, {{#if theme}}'{{ theme }}'{{else}}null{{/if}}, {
-    renderer: 'svg',
-    ssr: true,
-    width: {{ width }},
-    height: {{ height }}
-});
-
-chart.setOption({ animation: false, progressive: 0 });
-chart.setOption({{{ chart_option }}});
-chart.renderToSVGString();
+{{#each geo_maps}}{{{ this }}}
+{{/each}}
+(function () {
+    var chart = echarts.init(null, {{#if theme}}{{{ theme }}}{{else}}null{{/if}}, {
+        renderer: 'svg',
+        ssr: true,
+        width: {{ width }},
+        height: {{ height }}
+    });
+    try {
+        chart.setOption({ animation: false, progressive: 0 });
+        chart.setOption({{{ chart_option }}});
+        return chart.renderToSVGString();
+    } finally {
+        chart.dispose();
+    }
+})();
 "#;
 
+static SCRIPT_NAME: &str = "[chart.js]";
+
 #[cfg(feature = "ssr-raster")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
 pub use image::ImageFormat;
 
+/// Per-render overrides of the [`ImageRenderer`] settings.
+///
+/// The size applies to all outputs, the pixel ratio and the background color
+/// only to raster outputs.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RenderOptions {
+    width: Option<u32>,
+    height: Option<u32>,
+    pixel_ratio: Option<f32>,
+    background_color: Option<String>,
+}
+
+impl RenderOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Width of the chart, in CSS pixels
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the chart, in CSS pixels
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Number of image pixels per CSS pixel, e.g. `2.0` for retina displays
+    pub fn pixel_ratio(mut self, pixel_ratio: f32) -> Self {
+        self.pixel_ratio = Some(pixel_ratio);
+        self
+    }
+
+    /// CSS color filling the image behind the chart, which is transparent by
+    /// default
+    pub fn background_color<S: Into<String>>(mut self, background_color: S) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+}
+
+/// A rendered SVG along with the console messages logged while rendering it
+#[derive(Debug, PartialEq, Clone)]
+pub struct RenderReport {
+    pub svg: String,
+    pub messages: Vec<ConsoleMessage>,
+}
+
+impl RenderReport {
+    /// The warnings and errors logged while rendering
+    pub fn warnings(&self) -> impl Iterator<Item = &ConsoleMessage> {
+        self.messages
+            .iter()
+            .filter(|message| matches!(message.level, ConsoleLevel::Warn | ConsoleLevel::Error))
+    }
+}
+
 pub struct ImageRenderer {
-    js_runtime: JsRuntime,
+    runtime: Option<Runtime>,
+    heap_limit: Option<usize>,
+    timeout: Option<Duration>,
     #[cfg(feature = "ssr-raster")]
     fontdb: Arc<usvg::fontdb::Database>,
+    #[cfg(feature = "ssr-raster")]
+    font_aliases: BTreeMap<String, String>,
     theme: Theme,
     width: u32,
     height: u32,
 }
 
 impl ImageRenderer {
+    /// A renderer of `width` by `height` pixels. The JavaScript runtime is
+    /// created and ECharts loaded by the first render.
     pub fn new(width: u32, height: u32) -> Self {
-        let mut runtime = JsRuntime::new(RuntimeOptions::default());
-        runtime
-            .execute_script(
-                "[runtime.js]",
-                include_str!("../asset/runtime.js").to_string(),
-            )
-            .unwrap();
-        runtime
-            .execute_script(
-                "[echarts.js]",
-                include_str!("../asset/echarts-5.5.1.min.js").to_string(),
-            )
-            .unwrap();
-
         #[cfg(feature = "ssr-raster")]
         let mut fontdb = usvg::fontdb::Database::default();
         #[cfg(feature = "ssr-raster")]
@@ -70,9 +136,13 @@ impl ImageRenderer {
         }
 
         Self {
-            js_runtime: runtime,
+            runtime: None,
+            heap_limit: None,
+            timeout: None,
             #[cfg(feature = "ssr-raster")]
             fontdb: Arc::new(fontdb),
+            #[cfg(feature = "ssr-raster")]
+            font_aliases: BTreeMap::new(),
             theme: Theme::Default,
             width,
             height,
@@ -84,36 +154,233 @@ impl ImageRenderer {
         self
     }
 
+    /// Abort renders running longer than `timeout`, e.g. because a
+    /// `JsFunction` of the chart never returns
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Limit the heap of the JavaScript runtime to `bytes`, aborting renders
+    /// which need more. The next render creates the runtime with this limit,
+    /// and returns [`EchartsError::HeapLimitExceeded`] if the limit is too
+    /// small to load ECharts.
+    pub fn heap_limit(mut self, bytes: usize) -> Self {
+        self.heap_limit = Some(bytes);
+        self.reset_runtime();
+        self
+    }
+
+    /// Drop the JavaScript runtime, after a render left it unusable. A new
+    /// one is created by the next render.
+    fn reset_runtime(&mut self) {
+        self.runtime = None;
+    }
+
+    /// Create a JavaScript runtime with ECharts loaded if there is none
+    pub(crate) fn ensure_runtime(&mut self) -> Result<(), EchartsError> {
+        if self.runtime.is_none() {
+            self.runtime = Some(create_runtime(self.heap_limit)?);
+        }
+        Ok(())
+    }
+
+    /// Load a font file (TTF, OTF, TTC) for the raster and PDF outputs
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub fn load_font_file<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), EchartsError> {
+        Arc::make_mut(&mut self.fontdb)
+            .load_font_file(path)
+            .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))
+    }
+
+    /// Load the font files of a directory and its subdirectories for the
+    /// raster and PDF outputs
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub fn load_fonts_dir<P: AsRef<std::path::Path>>(&mut self, path: P) {
+        Arc::make_mut(&mut self.fontdb).load_fonts_dir(path);
+    }
+
+    /// Load a font (TTF, OTF, TTC) from memory for the raster and PDF outputs
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub fn load_font_data(&mut self, data: Vec<u8>) {
+        Arc::make_mut(&mut self.fontdb).load_font_data(data);
+    }
+
+    /// Render text using the font family `alias`, e.g. `"Corporate"` or
+    /// the generic `"sans-serif"`, with the loaded font family `family`
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub fn set_font_alias<A: Into<String>, F: Into<String>>(&mut self, alias: A, family: F) {
+        self.font_aliases.insert(alias.into(), family.into());
+    }
+
     /// Render chart to an SVG String
+    ///
+    /// GeoJSON maps of the chart are registered before rendering. Charts with
+    /// SVG maps return an error because parsing them requires a DOM, which is
+    /// not available in the server-side runtime.
     pub fn render(&mut self, chart: &Chart) -> Result<String, EchartsError> {
-        let (theme, theme_source) = self.theme.to_str();
+        self.render_with(chart, &RenderOptions::default())
+    }
+
+    /// Render chart to an SVG String, overriding the size of the renderer
+    pub fn render_with(
+        &mut self,
+        chart: &Chart,
+        options: &RenderOptions,
+    ) -> Result<String, EchartsError> {
+        self.render_report_with(chart, options)
+            .map(|report| report.svg)
+    }
+
+    /// Render chart to an SVG String, along with the console messages logged
+    /// while rendering it
+    ///
+    /// Console messages are also forwarded to the [`log`] facade, with the
+    /// `charming` target, whichever render method is used. The messages of a
+    /// failed render are attached to its [`EchartsError::JsException`],
+    /// [`EchartsError::Timeout`] or [`EchartsError::HeapLimitExceeded`]
+    /// error.
+    ///
+    /// The bundled ECharts is its production build, which strips the
+    /// development warnings of ECharts, e.g. about unknown options, so only
+    /// errors and the messages of the chart's `JsFunction`s are captured.
+    pub fn render_report(&mut self, chart: &Chart) -> Result<RenderReport, EchartsError> {
+        self.render_report_with(chart, &RenderOptions::default())
+    }
+
+    /// Render chart to an SVG String along with the console messages,
+    /// overriding the size of the renderer
+    pub fn render_report_with(
+        &mut self,
+        chart: &Chart,
+        options: &RenderOptions,
+    ) -> Result<RenderReport, EchartsError> {
+        let chart_option = chart.to_string();
         let code = Handlebars::new()
             .render_template(
                 CODE_TEMPLATE,
                 &serde_json::json!({
-                    "theme": theme,
-                    "theme_source": theme_source,
-                    "width": self.width,
-                    "height": self.height,
-                    "chart_option": chart.to_string(),
+                    "theme": self.theme.name_literal(),
+                    "theme_source": self.theme.source()?,
+                    "width": options.width.unwrap_or(self.width),
+                    "height": options.height.unwrap_or(self.height),
+                    "chart_option": chart_option,
+                    "geo_maps": geo_map_scripts(chart)?,
                 }),
             )
-            .expect("Failed to render template");
-        let result = self.js_runtime.execute_script("[anon]", code);
+            .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))?;
 
+        self.ensure_runtime()?;
+        let result = self.execute(code.clone());
+        let messages = self.take_console_messages();
         match result {
-            Ok(global) => {
-                let scope = &mut self.js_runtime.handle_scope();
+            Ok(svg) => Ok(RenderReport { svg, messages }),
+            Err(ExecutionError::Timeout) => {
+                self.reset_runtime();
+                Err(EchartsError::Timeout {
+                    timeout: self.timeout.unwrap_or_default(),
+                    console: messages,
+                })
+            }
+            Err(ExecutionError::HeapLimitExceeded) => {
+                self.reset_runtime();
+                Err(EchartsError::HeapLimitExceeded {
+                    limit: self.heap_limit.unwrap_or_default(),
+                    console: messages,
+                })
+            }
+            Err(ExecutionError::Js(error)) => Err(EchartsError::JsException(js_exception(
+                &error,
+                &code,
+                &chart_option,
+                messages,
+            ))),
+            Err(ExecutionError::Runtime(error)) => Err(EchartsError::JsRuntimeError(error)),
+        }
+    }
+
+    /// Collect the console messages logged since the last call, and forward
+    /// them to the log facade
+    fn take_console_messages(&mut self) -> Vec<ConsoleMessage> {
+        let messages = self
+            .runtime
+            .as_mut()
+            .and_then(|runtime| {
+                let global = runtime
+                    .js_runtime
+                    .execute_script("[console.js]", "__charmingTakeConsoleMessages()")
+                    .ok()?;
+                let scope = &mut runtime.js_runtime.handle_scope();
                 let local = v8::Local::new(scope, global);
-                let value = serde_v8::from_v8::<serde_json::Value>(scope, local);
+                serde_v8::from_v8::<Vec<ConsoleMessage>>(scope, local).ok()
+            })
+            .unwrap_or_default();
+        log_console_messages(&messages);
+        messages
+    }
 
-                match value {
-                    Ok(value) => Ok(value.as_str().unwrap().to_string()),
-                    Err(error) => Err(EchartsError::JsRuntimeError(error.to_string())),
+    /// Run a script returning a string, within the execution limits
+    fn execute(&mut self, code: String) -> Result<String, ExecutionError> {
+        let timeout = self.timeout;
+        let Some(runtime) = self.runtime.as_mut() else {
+            return Err(ExecutionError::Runtime(
+                "The JavaScript runtime is not initialized".to_string(),
+            ));
+        };
+        let watchdog = timeout.map(|timeout| {
+            let isolate = runtime.js_runtime.v8_isolate().thread_safe_handle();
+            let (done, wait) = mpsc::channel::<()>();
+            let watchdog = std::thread::spawn(move || {
+                let timed_out = wait.recv_timeout(timeout) == Err(mpsc::RecvTimeoutError::Timeout);
+                if timed_out {
+                    isolate.terminate_execution();
                 }
-            }
-            Err(error) => Err(EchartsError::JsRuntimeError(error.to_string())),
+                timed_out
+            });
+            (done, watchdog)
+        });
+
+        let result = runtime.js_runtime.execute_script(SCRIPT_NAME, code);
+
+        let timed_out = watchdog.is_some_and(|(done, watchdog)| {
+            drop(done);
+            watchdog.join().unwrap_or(false)
+        });
+        if timed_out || runtime.heap_limit_exceeded.get() {
+            // Let the console messages be collected before the runtime is
+            // dropped.
+            runtime.js_runtime.v8_isolate().cancel_terminate_execution();
+            return Err(if timed_out {
+                ExecutionError::Timeout
+            } else {
+                ExecutionError::HeapLimitExceeded
+            });
         }
+
+        let global = result.map_err(|error| ExecutionError::Js(Box::new(error)))?;
+        let scope = &mut runtime.js_runtime.handle_scope();
+        let local = v8::Local::new(scope, global);
+        match serde_v8::from_v8::<serde_json::Value>(scope, local) {
+            Ok(serde_json::Value::String(svg)) => Ok(svg),
+            Ok(value) => Err(ExecutionError::Runtime(format!(
+                "Expected the rendered SVG string, got {value}"
+            ))),
+            Err(error) => Err(ExecutionError::Runtime(error.to_string())),
+        }
+    }
+
+    /// Render the given timeline frame of a chart to an SVG String
+    ///
+    /// Returns an error if the chart has no timeline or no such frame.
+    pub fn render_frame(&mut self, chart: &Chart, frame: usize) -> Result<String, EchartsError> {
+        self.render(&chart.with_timeline_frame(frame)?)
     }
 
     /// Render a chart to a given image format in bytes
@@ -124,36 +391,76 @@ impl ImageRenderer {
         image_format: ImageFormat,
         chart: &Chart,
     ) -> Result<Vec<u8>, EchartsError> {
-        let svg = self.render(chart)?;
+        self.render_format_with(image_format, chart, &RenderOptions::default())
+    }
 
-        let img = self.render_svg_to_buf(&svg)?;
+    /// Render a chart to a given image format in bytes, with the size, pixel
+    /// ratio and background color of the given options
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub fn render_format_with(
+        &mut self,
+        image_format: ImageFormat,
+        chart: &Chart,
+        options: &RenderOptions,
+    ) -> Result<Vec<u8>, EchartsError> {
+        let svg = self.render_with(chart, options)?;
+
+        let img = self.render_svg_to_buf(&svg, options)?;
 
         // give buf initial capacity of: width * height * num of channels for RGBA + room for headers/metadata
-        let estimated_capacity = self.width * self.height * 4 + 1024;
+        let estimated_capacity = img.width() * img.height() * 4 + 1024;
         let mut buf = Vec::with_capacity(estimated_capacity as usize);
         img.write_to(&mut Cursor::new(&mut buf), image_format)
             .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))?;
         Ok(buf)
     }
 
+    /// Render the given timeline frame of a chart to a given image format in bytes
+    ///
+    /// Returns an error if the chart has no timeline or no such frame.
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub fn render_format_frame(
+        &mut self,
+        image_format: ImageFormat,
+        chart: &Chart,
+        frame: usize,
+    ) -> Result<Vec<u8>, EchartsError> {
+        self.render_format(image_format, &chart.with_timeline_frame(frame)?)
+    }
+
     /// Given an svg str, render it into an [`image::ImageBuffer`]
     #[cfg(feature = "ssr-raster")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
-    fn render_svg_to_buf(&mut self, svg: &str) -> Result<image::RgbaImage, EchartsError> {
-        let mut pixels =
-            Pixmap::new(self.width, self.height).ok_or(EchartsError::ImageRenderingError(
-                "Rendered image cannot be greater than i32::MAX/4".to_string(),
-            ))?;
+    fn render_svg_to_buf(
+        &mut self,
+        svg: &str,
+        options: &RenderOptions,
+    ) -> Result<image::RgbaImage, EchartsError> {
+        let pixel_ratio = options.pixel_ratio.unwrap_or(1.0);
+        let (width, height) = scaled_size(
+            options.width.unwrap_or(self.width),
+            options.height.unwrap_or(self.height),
+            pixel_ratio,
+        )?;
 
-        let options = usvg::Options {
-            fontdb: Arc::clone(&self.fontdb),
-            ..Default::default()
-        };
-        let tree = usvg::Tree::from_data(svg.as_bytes(), &options)
+        let mut pixels = Pixmap::new(width, height).ok_or(EchartsError::ImageRenderingError(
+            "Rendered image cannot be greater than i32::MAX/4".to_string(),
+        ))?;
+        if let Some(background_color) = &options.background_color {
+            pixels.fill(parse_color(background_color)?);
+        }
+
+        let tree = usvg::Tree::from_data(svg.as_bytes(), &self.usvg_options())
             .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))?;
-        resvg::render(&tree, usvg::Transform::identity(), &mut pixels.as_mut());
+        resvg::render(
+            &tree,
+            usvg::Transform::from_scale(pixel_ratio, pixel_ratio),
+            &mut pixels.as_mut(),
+        );
 
-        let img = RgbaImage::from_vec(self.width, self.height, pixels.take()).ok_or(
+        let img = RgbaImage::from_vec(width, height, pixels.take()).ok_or(
             EchartsError::ImageRenderingError(
                 "Could not create ImageBuffer from bytes".to_string(),
             ),
@@ -162,6 +469,21 @@ impl ImageRenderer {
         Ok(img)
     }
 
+    /// Options to parse rendered SVGs with the fonts of the renderer
+    #[cfg(feature = "ssr-raster")]
+    fn usvg_options(&self) -> usvg::Options<'_> {
+        let mut options = usvg::Options {
+            fontdb: Arc::clone(&self.fontdb),
+            ..Default::default()
+        };
+        if !self.font_aliases.is_empty() {
+            let aliases = &self.font_aliases;
+            options.font_resolver.select_font =
+                Box::new(move |font, fontdb| select_font(font, fontdb, aliases));
+        }
+        options
+    }
+
     /// Render and save chart as an SVG
     pub fn save<P: AsRef<std::path::Path>>(
         &mut self,
@@ -183,10 +505,331 @@ impl ImageRenderer {
         path: P,
     ) -> Result<(), EchartsError> {
         let svg = self.render(chart)?;
-        let img = self.render_svg_to_buf(&svg)?;
+        let img = self.render_svg_to_buf(&svg, &RenderOptions::default())?;
         img.save_with_format(path, image_format)
             .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))
     }
+
+    /// Render a chart to a single page PDF document in bytes
+    ///
+    /// The page has the size of the chart, with one CSS pixel per 1/96 inch.
+    /// Text is embedded with the fonts used for rasterization.
+    #[cfg(feature = "ssr-pdf")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-pdf")))]
+    pub fn render_pdf(&mut self, chart: &Chart) -> Result<Vec<u8>, EchartsError> {
+        self.render_pdf_pages(std::slice::from_ref(chart))
+    }
+
+    /// Render charts to a PDF document in bytes, one chart per page
+    #[cfg(feature = "ssr-pdf")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-pdf")))]
+    pub fn render_pdf_pages(&mut self, charts: &[Chart]) -> Result<Vec<u8>, EchartsError> {
+        let svgs = charts
+            .iter()
+            .map(|chart| self.render(chart))
+            .collect::<Result<Vec<_>, _>>()?;
+        svg_pages_to_pdf(&svgs, &self.usvg_options())
+    }
+
+    /// Render and save chart as a single page PDF document
+    #[cfg(feature = "ssr-pdf")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-pdf")))]
+    pub fn save_pdf<P: AsRef<std::path::Path>>(
+        &mut self,
+        chart: &Chart,
+        path: P,
+    ) -> Result<(), EchartsError> {
+        let pdf = self.render_pdf(chart)?;
+        std::fs::write(path, pdf)
+            .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))
+    }
+}
+
+enum ExecutionError {
+    Timeout,
+    HeapLimitExceeded,
+    Js(Box<JsError>),
+    Runtime(String),
+}
+
+/// A JavaScript runtime with ECharts loaded, and a flag set when the
+/// runtime reaches its heap limit
+struct Runtime {
+    js_runtime: JsRuntime,
+    heap_limit_exceeded: Rc<Cell<bool>>,
+}
+
+/// Create a runtime and load ECharts, which fails if the heap limit is too
+/// small for it
+fn create_runtime(heap_limit: Option<usize>) -> Result<Runtime, EchartsError> {
+    let mut runtime = JsRuntime::new(RuntimeOptions {
+        create_params: heap_limit
+            .map(|heap_limit| v8::CreateParams::default().heap_limits(0, heap_limit)),
+        ..Default::default()
+    });
+
+    let heap_limit_exceeded = Rc::new(Cell::new(false));
+    if heap_limit.is_some() {
+        let isolate = runtime.v8_isolate().thread_safe_handle();
+        let exceeded = Rc::clone(&heap_limit_exceeded);
+        // V8 aborts the process when the heap is full, so stop the script
+        // and give it room to unwind.
+        runtime.add_near_heap_limit_callback(move |current_limit, _| {
+            exceeded.set(true);
+            isolate.terminate_execution();
+            current_limit * 2
+        });
+    }
+
+    for (name, source) in [
+        ("[runtime.js]", include_str!("../asset/runtime.js")),
+        (
+            "[echarts.js]",
+            include_str!("../asset/echarts-5.5.1.min.js"),
+        ),
+    ] {
+        if let Err(error) = runtime.execute_script(name, source) {
+            return Err(if heap_limit_exceeded.get() {
+                EchartsError::HeapLimitExceeded {
+                    limit: heap_limit.unwrap_or_default(),
+                    console: vec![],
+                }
+            } else {
+                EchartsError::JsRuntimeError(error.to_string())
+            });
+        }
+    }
+
+    Ok(Runtime {
+        js_runtime: runtime,
+        heap_limit_exceeded,
+    })
+}
+
+/// The `echarts.registerMap` calls of the geo maps of a chart, or an error
+/// naming the first SVG map, which the runtime cannot parse
+fn geo_map_scripts(chart: &Chart) -> Result<Vec<String>, EchartsError> {
+    let mut scripts = vec![];
+    for geo_map in chart.geo_maps() {
+        let Some((name, _)) = geo_map.register_map_args() else {
+            continue;
+        };
+        if geo_map.is_svg() {
+            return Err(EchartsError::ImageRenderingError(format!(
+                "The SVG map \"{name}\" cannot be rendered on the server, which has no DOM to parse it"
+            )));
+        }
+        scripts.extend(geo_map.register_map_script());
+    }
+    Ok(scripts)
+}
+
+/// Forward console messages to the log facade, with the `charming` target
+fn log_console_messages(messages: &[ConsoleMessage]) {
+    for message in messages {
+        let level = match message.level {
+            ConsoleLevel::Debug => log::Level::Debug,
+            ConsoleLevel::Log | ConsoleLevel::Info => log::Level::Info,
+            ConsoleLevel::Warn => log::Level::Warn,
+            ConsoleLevel::Error => log::Level::Error,
+        };
+        log::log!(target: "charming", level, "{}", message.message);
+    }
+}
+
+/// Convert an exception of the chart script, locating the option of the
+/// `JsFunction` it was thrown from
+fn js_exception(
+    error: &JsError,
+    code: &str,
+    chart_option: &str,
+    console: Vec<ConsoleMessage>,
+) -> JsException {
+    let message = match (&error.name, &error.message) {
+        (Some(name), Some(message)) => format!("{name}: {message}"),
+        _ => error.exception_message.clone(),
+    };
+    let option_start = code.find(chart_option);
+    let option_path = error
+        .frames
+        .iter()
+        .find(|frame| frame.file_name.as_deref() == Some(SCRIPT_NAME))
+        .and_then(|frame| {
+            let offset = code_offset(
+                code,
+                frame.line_number? as usize,
+                frame.column_number? as usize,
+            )?;
+            option_path_at(chart_option, offset.checked_sub(option_start?)?)
+        });
+
+    JsException {
+        message,
+        stack: error.stack.clone(),
+        option_path,
+        console,
+    }
+}
+
+/// Byte offset of a 1-based line and column in `code`
+fn code_offset(code: &str, line: usize, column: usize) -> Option<usize> {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        code.match_indices('\n').nth(line - 2)?.0 + 1
+    };
+    let column_offset = code[line_start..]
+        .char_indices()
+        .nth(column.checked_sub(1)?)?
+        .0;
+    Some(line_start + column_offset)
+}
+
+/// Query the font database like the default usvg font selector, with the
+/// font families replaced by their aliases
+#[cfg(feature = "ssr-raster")]
+fn select_font(
+    font: &usvg::Font,
+    fontdb: &mut Arc<usvg::fontdb::Database>,
+    aliases: &BTreeMap<String, String>,
+) -> Option<usvg::fontdb::ID> {
+    use usvg::fontdb::{Family, Query, Stretch, Style, Weight};
+
+    let mut families: Vec<Family> = font
+        .families()
+        .iter()
+        .map(|family| {
+            let name = match family {
+                usvg::FontFamily::Serif => "serif",
+                usvg::FontFamily::SansSerif => "sans-serif",
+                usvg::FontFamily::Cursive => "cursive",
+                usvg::FontFamily::Fantasy => "fantasy",
+                usvg::FontFamily::Monospace => "monospace",
+                usvg::FontFamily::Named(name) => name,
+            };
+            match (aliases.get(name), family) {
+                (Some(alias), _) => Family::Name(alias),
+                (None, usvg::FontFamily::Serif) => Family::Serif,
+                (None, usvg::FontFamily::SansSerif) => Family::SansSerif,
+                (None, usvg::FontFamily::Cursive) => Family::Cursive,
+                (None, usvg::FontFamily::Fantasy) => Family::Fantasy,
+                (None, usvg::FontFamily::Monospace) => Family::Monospace,
+                (None, usvg::FontFamily::Named(name)) => Family::Name(name),
+            }
+        })
+        .collect();
+    families.push(Family::Serif);
+
+    let stretch = match font.stretch() {
+        usvg::FontStretch::UltraCondensed => Stretch::UltraCondensed,
+        usvg::FontStretch::ExtraCondensed => Stretch::ExtraCondensed,
+        usvg::FontStretch::Condensed => Stretch::Condensed,
+        usvg::FontStretch::SemiCondensed => Stretch::SemiCondensed,
+        usvg::FontStretch::Normal => Stretch::Normal,
+        usvg::FontStretch::SemiExpanded => Stretch::SemiExpanded,
+        usvg::FontStretch::Expanded => Stretch::Expanded,
+        usvg::FontStretch::ExtraExpanded => Stretch::ExtraExpanded,
+        usvg::FontStretch::UltraExpanded => Stretch::UltraExpanded,
+    };
+    let style = match font.style() {
+        usvg::FontStyle::Normal => Style::Normal,
+        usvg::FontStyle::Italic => Style::Italic,
+        usvg::FontStyle::Oblique => Style::Oblique,
+    };
+
+    fontdb.query(&Query {
+        families: &families,
+        weight: Weight(font.weight()),
+        stretch,
+        style,
+    })
+}
+
+/// Assemble SVGs into a PDF document, one SVG per page
+#[cfg(feature = "ssr-pdf")]
+fn svg_pages_to_pdf(svgs: &[String], options: &usvg::Options) -> Result<Vec<u8>, EchartsError> {
+    use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref};
+
+    if svgs.is_empty() {
+        return Err(EchartsError::ImageRenderingError(
+            "A PDF document needs at least one chart".to_string(),
+        ));
+    }
+
+    let mut alloc = Ref::new(1);
+    let catalog_id = alloc.bump();
+    let page_tree_id = alloc.bump();
+    let mut page_ids = Vec::with_capacity(svgs.len());
+    let mut pdf = Pdf::new();
+
+    for svg in svgs {
+        let tree = usvg::Tree::from_data(svg.as_bytes(), options)
+            .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))?;
+        let (chunk, svg_id) = svg2pdf::to_chunk(&tree, svg2pdf::ConversionOptions::default())
+            .map_err(|error| EchartsError::ImageRenderingError(error.to_string()))?;
+
+        // The chunk numbers its objects from 1, move them after ours.
+        let mut refs = HashMap::new();
+        let chunk = chunk.renumber(|old| *refs.entry(old).or_insert_with(|| alloc.bump()));
+        let svg_id = refs[&svg_id];
+
+        // CSS pixels are 1/96 inch, PDF points 1/72 inch.
+        let width = tree.size().width() * 0.75;
+        let height = tree.size().height() * 0.75;
+        let page_id = alloc.bump();
+        let content_id = alloc.bump();
+        let svg_name = Name(b"S1");
+
+        let mut page = pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, width, height));
+        page.parent(page_tree_id);
+        page.contents(content_id);
+        page.resources().x_objects().pair(svg_name, svg_id);
+        page.finish();
+
+        let mut content = Content::new();
+        content
+            .transform([width, 0.0, 0.0, height, 0.0, 0.0])
+            .x_object(svg_name);
+        pdf.stream(content_id, &content.finish());
+        pdf.extend(&chunk);
+        page_ids.push(page_id);
+    }
+
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().copied())
+        .count(page_ids.len() as i32);
+    Ok(pdf.finish())
+}
+
+/// Size in image pixels of a chart of the given size in CSS pixels, rounded
+/// to the nearest pixel
+#[cfg(feature = "ssr-raster")]
+fn scaled_size(width: u32, height: u32, pixel_ratio: f32) -> Result<(u32, u32), EchartsError> {
+    if !(pixel_ratio.is_finite() && pixel_ratio > 0.0) {
+        return Err(EchartsError::ImageRenderingError(format!(
+            "Invalid pixel ratio {pixel_ratio}"
+        )));
+    }
+    Ok((
+        (width as f32 * pixel_ratio).round() as u32,
+        (height as f32 * pixel_ratio).round() as u32,
+    ))
+}
+
+/// Parse a CSS color into a color to fill a pixmap with
+#[cfg(feature = "ssr-raster")]
+fn parse_color(color: &str) -> Result<resvg::tiny_skia::Color, EchartsError> {
+    let color: svgtypes::Color = color
+        .parse()
+        .map_err(|error: svgtypes::Error| EchartsError::ImageRenderingError(error.to_string()))?;
+    Ok(resvg::tiny_skia::Color::from_rgba8(
+        color.red,
+        color.green,
+        color.blue,
+        color.alpha,
+    ))
 }
 
 #[cfg(all(
@@ -258,3 +901,225 @@ fn font_exists(fontdb: &usvg::fontdb::Database, family: &str) -> bool {
         })
         .is_some()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Records the messages logged with the `charming` target
+    struct TestLogger(Mutex<Vec<(log::Level, String)>>);
+
+    impl log::Log for TestLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == "charming"
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                let mut records = self.0.lock().unwrap();
+                records.push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: TestLogger = TestLogger(Mutex::new(vec![]));
+
+    fn message(level: ConsoleLevel, message: &str) -> ConsoleMessage {
+        ConsoleMessage {
+            level,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn console_messages_are_logged() {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        log_console_messages(&[
+            message(ConsoleLevel::Debug, "debug message"),
+            message(ConsoleLevel::Log, "log message"),
+            message(ConsoleLevel::Info, "info message"),
+            message(ConsoleLevel::Warn, "warn message"),
+            message(ConsoleLevel::Error, "error message"),
+        ]);
+
+        assert_eq!(
+            *LOGGER.0.lock().unwrap(),
+            vec![
+                (log::Level::Debug, "debug message".to_string()),
+                (log::Level::Info, "log message".to_string()),
+                (log::Level::Info, "info message".to_string()),
+                (log::Level::Warn, "warn message".to_string()),
+                (log::Level::Error, "error message".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn console_messages_deserialize() {
+        let messages: Vec<ConsoleMessage> = serde_json::from_str(
+            r#"[{ "level": "warn", "message": "[ECharts] unknown series" }, { "level": "log", "message": "1 2" }]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            messages,
+            vec![
+                message(ConsoleLevel::Warn, "[ECharts] unknown series"),
+                message(ConsoleLevel::Log, "1 2"),
+            ]
+        );
+        assert!(serde_json::from_str::<ConsoleMessage>(
+            r#"{ "level": "trace", "message": "unknown level" }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn render_report_warnings() {
+        let report = RenderReport {
+            svg: "<svg></svg>".to_string(),
+            messages: vec![
+                message(ConsoleLevel::Info, "info"),
+                message(ConsoleLevel::Warn, "warn"),
+                message(ConsoleLevel::Debug, "debug"),
+                message(ConsoleLevel::Error, "error"),
+            ],
+        };
+        assert_eq!(
+            report.warnings().collect::<Vec<_>>(),
+            vec![
+                &message(ConsoleLevel::Warn, "warn"),
+                &message(ConsoleLevel::Error, "error"),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_options() {
+        let options = RenderOptions::new()
+            .width(800)
+            .height(600)
+            .pixel_ratio(2.0)
+            .background_color("#fff");
+        assert_eq!(
+            options,
+            RenderOptions {
+                width: Some(800),
+                height: Some(600),
+                pixel_ratio: Some(2.0),
+                background_color: Some("#fff".to_string()),
+            }
+        );
+        assert_eq!(RenderOptions::new(), RenderOptions::default());
+    }
+
+    #[cfg(feature = "ssr-raster")]
+    #[test]
+    fn scaled_sizes() {
+        assert_eq!(scaled_size(600, 400, 1.0).unwrap(), (600, 400));
+        assert_eq!(scaled_size(600, 400, 2.0).unwrap(), (1200, 800));
+        assert_eq!(scaled_size(601, 333, 1.5).unwrap(), (902, 500));
+        assert_eq!(scaled_size(100, 101, 0.25).unwrap(), (25, 25));
+        for pixel_ratio in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(matches!(
+                scaled_size(600, 400, pixel_ratio),
+                Err(EchartsError::ImageRenderingError(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "ssr-raster")]
+    #[test]
+    fn parse_colors() {
+        let rgba = |color: &str| {
+            let color = parse_color(color).unwrap().to_color_u8();
+            (color.red(), color.green(), color.blue(), color.alpha())
+        };
+        assert_eq!(rgba("#ff8000"), (255, 128, 0, 255));
+        assert_eq!(rgba("white"), (255, 255, 255, 255));
+        assert_eq!(rgba("rgb(10, 20, 30)"), (10, 20, 30, 255));
+        assert_eq!(rgba("rgba(0, 0, 255, 0.5)"), (0, 0, 255, 128));
+        assert_eq!(rgba("transparent").3, 0);
+
+        for color in ["", "not a color", "#12345g", "rgb(1, 2)"] {
+            assert!(matches!(
+                parse_color(color),
+                Err(EchartsError::ImageRenderingError(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "ssr-pdf")]
+    #[test]
+    fn pdf_pages() {
+        let svg = |width: u32, height: u32, fill: &str| {
+            format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}"><rect x="10" y="10" width="50" height="40" fill="{fill}"/><circle cx="80" cy="60" r="20" fill="{fill}"/></svg>"#
+            )
+        };
+        let svgs = vec![
+            svg(400, 300, "#5470c6"),
+            svg(800, 600, "#91cc75"),
+            svg(200, 100, "#fac858"),
+        ];
+        let pdf = svg_pages_to_pdf(&svgs, &usvg::Options::default()).unwrap();
+
+        let document = lopdf::Document::load_mem(&pdf).unwrap();
+        let pages = document.get_pages();
+        assert_eq!(pages.len(), 3);
+        let media_boxes: Vec<Vec<f32>> = pages
+            .values()
+            .map(|&id| {
+                document
+                    .get_object(id)
+                    .and_then(|page| page.as_dict())
+                    .and_then(|page| page.get(b"MediaBox"))
+                    .and_then(|media_box| media_box.as_array())
+                    .unwrap()
+                    .iter()
+                    .map(|value| value.as_float().unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            media_boxes,
+            vec![
+                vec![0.0, 0.0, 300.0, 225.0],
+                vec![0.0, 0.0, 600.0, 450.0],
+                vec![0.0, 0.0, 150.0, 75.0],
+            ]
+        );
+        // Every page draws its own SVG object.
+        let mut x_objects = std::collections::HashSet::new();
+        for &id in pages.values() {
+            let resources = document
+                .get_object(id)
+                .and_then(|page| page.as_dict())
+                .and_then(|page| page.get(b"Resources"))
+                .and_then(|resources| resources.as_dict())
+                .unwrap();
+            let x_object = resources
+                .get(b"XObject")
+                .and_then(|x_objects| x_objects.as_dict())
+                .and_then(|x_objects| x_objects.get(b"S1"))
+                .and_then(|x_object| x_object.as_reference())
+                .unwrap();
+            assert!(document.get_object(x_object).is_ok());
+            x_objects.insert(x_object);
+        }
+        assert_eq!(x_objects.len(), 3);
+    }
+
+    #[cfg(feature = "ssr-pdf")]
+    #[test]
+    fn pdf_needs_pages() {
+        assert!(matches!(
+            svg_pages_to_pdf(&[], &usvg::Options::default()),
+            Err(EchartsError::ImageRenderingError(_))
+        ));
+    }
+}
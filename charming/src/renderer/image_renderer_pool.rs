@@ -0,0 +1,441 @@
+use std::{
+    future::Future,
+    panic::{catch_unwind, AssertUnwindSafe},
+    pin::Pin,
+    sync::{mpsc, Arc, Mutex},
+    task::{Context, Poll, Waker},
+    thread::JoinHandle,
+};
+
+#[cfg(feature = "ssr-raster")]
+use super::ImageFormat;
+use super::{ImageRenderer, RenderOptions, RenderReport};
+use crate::{Chart, EchartsError};
+
+type Job<R> = Box<dyn FnOnce(&mut R) + Send>;
+type Factory<R> = dyn Fn() -> R + Send + Sync;
+
+/// A pool of [`ImageRenderer`]s that can be shared between threads.
+///
+/// `ImageRenderer` owns a JavaScript runtime which cannot leave the thread it
+/// was created on. The pool runs a fixed number of worker threads, each
+/// owning a warm renderer built by the given factory, and hands out renders
+/// to the first idle worker. The render methods are `async` and do not block
+/// the calling thread, so the pool can be used from any async runtime.
+///
+/// ```rust,no_run
+/// use charming::{Chart, ImageRenderer, ImageRendererPool, theme::Theme};
+/// use std::sync::Arc;
+///
+/// let pool = Arc::new(ImageRendererPool::new(4, || {
+///     ImageRenderer::new(800, 600).theme(Theme::Dark)
+/// }));
+///
+/// async fn handler(pool: Arc<ImageRendererPool>, chart: Chart) -> String {
+///     pool.render(&chart).await.unwrap()
+/// }
+/// ```
+pub struct ImageRendererPool {
+    pool: WorkerPool<ImageRenderer>,
+}
+
+impl ImageRendererPool {
+    /// Start `workers` worker threads, each calling `factory` once to create
+    /// its renderer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `workers` is zero.
+    pub fn new<F>(workers: usize, factory: F) -> Self
+    where
+        F: Fn() -> ImageRenderer + Send + Sync + 'static,
+    {
+        assert!(
+            workers > 0,
+            "an ImageRendererPool needs at least one worker"
+        );
+
+        Self {
+            pool: WorkerPool::new(workers, move || {
+                let mut renderer = factory();
+                // Load ECharts before the first job. A failure is reported
+                // by the first render, which tries again.
+                let _ = renderer.ensure_runtime();
+                renderer
+            }),
+        }
+    }
+
+    /// Number of worker threads of the pool
+    pub fn workers(&self) -> usize {
+        self.pool.workers.len()
+    }
+
+    /// Render chart to an SVG String, see [`ImageRenderer::render`]
+    pub async fn render(&self, chart: &Chart) -> Result<String, EchartsError> {
+        let chart = chart.clone();
+        self.submit(move |renderer| renderer.render(&chart)).await
+    }
+
+    /// Render chart to an SVG String, see [`ImageRenderer::render_with`]
+    pub async fn render_with(
+        &self,
+        chart: &Chart,
+        options: &RenderOptions,
+    ) -> Result<String, EchartsError> {
+        let chart = chart.clone();
+        let options = options.clone();
+        self.submit(move |renderer| renderer.render_with(&chart, &options))
+            .await
+    }
+
+    /// Render chart to an SVG String along with the console messages, see
+    /// [`ImageRenderer::render_report`]
+    pub async fn render_report(&self, chart: &Chart) -> Result<RenderReport, EchartsError> {
+        let chart = chart.clone();
+        self.submit(move |renderer| renderer.render_report(&chart))
+            .await
+    }
+
+    /// Render a chart to a given image format in bytes, see
+    /// [`ImageRenderer::render_format`]
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub async fn render_format(
+        &self,
+        image_format: ImageFormat,
+        chart: &Chart,
+    ) -> Result<Vec<u8>, EchartsError> {
+        let chart = chart.clone();
+        self.submit(move |renderer| renderer.render_format(image_format, &chart))
+            .await
+    }
+
+    /// Render a chart to a given image format in bytes, see
+    /// [`ImageRenderer::render_format_with`]
+    #[cfg(feature = "ssr-raster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ssr-raster")))]
+    pub async fn render_format_with(
+        &self,
+        image_format: ImageFormat,
+        chart: &Chart,
+        options: &RenderOptions,
+    ) -> Result<Vec<u8>, EchartsError> {
+        let chart = chart.clone();
+        let options = options.clone();
+        self.submit(move |renderer| renderer.render_format_with(image_format, &chart, &options))
+            .await
+    }
+
+    /// Run `job` on the next idle worker and wait for its result
+    fn submit<T, F>(&self, job: F) -> Reply<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut ImageRenderer) -> Result<T, EchartsError> + Send + 'static,
+    {
+        self.pool.submit(job)
+    }
+}
+
+// The pool and its render futures are meant to be shared between threads.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ImageRendererPool>();
+    assert_send_sync::<Reply<String>>();
+};
+
+/// Worker threads, each owning a renderer of type `R`, running the jobs
+/// submitted to the pool. It is generic so it can be tested without a
+/// JavaScript runtime.
+struct WorkerPool<R> {
+    sender: Option<mpsc::Sender<Job<R>>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl<R: 'static> WorkerPool<R> {
+    fn new<F>(workers: usize, factory: F) -> Self
+    where
+        F: Fn() -> R + Send + Sync + 'static,
+    {
+        let factory: Arc<Factory<R>> = Arc::new(factory);
+        let (sender, receiver) = mpsc::channel::<Job<R>>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..workers)
+            .map(|i| {
+                let factory = Arc::clone(&factory);
+                let receiver = Arc::clone(&receiver);
+                std::thread::Builder::new()
+                    .name(format!("charming-renderer-{i}"))
+                    .spawn(move || worker(factory, receiver))
+                    .expect("failed to spawn renderer thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    fn submit<T, F>(&self, job: F) -> Reply<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut R) -> Result<T, EchartsError> + Send + 'static,
+    {
+        let (responder, reply) = reply();
+        let job: Job<R> = Box::new(move |renderer| responder.send(job(renderer)));
+        if let Some(sender) = &self.sender {
+            // If every worker is gone, the job is dropped along with its
+            // responder, which fails the reply.
+            let _ = sender.send(job);
+        }
+        reply
+    }
+}
+
+impl<R> Drop for WorkerPool<R> {
+    fn drop(&mut self) {
+        // Closing the channel lets the workers finish the queued renders and exit.
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker<R>(factory: Arc<Factory<R>>, receiver: Arc<Mutex<mpsc::Receiver<Job<R>>>>) {
+    let mut renderer = factory();
+    loop {
+        let job = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(job) = job else {
+            return;
+        };
+        // A panicking render drops its responder, which fails the render, and
+        // may leave the runtime in a broken state, so it is replaced.
+        if catch_unwind(AssertUnwindSafe(|| job(&mut renderer))).is_err() {
+            renderer = factory();
+        }
+    }
+}
+
+struct Slot<T> {
+    result: Option<Result<T, EchartsError>>,
+    waker: Option<Waker>,
+}
+
+fn reply<T>() -> (Responder<T>, Reply<T>) {
+    let slot = Arc::new(Mutex::new(Slot {
+        result: None,
+        waker: None,
+    }));
+    (
+        Responder {
+            slot: Some(Arc::clone(&slot)),
+        },
+        Reply { slot },
+    )
+}
+
+/// Sending half of a [`Reply`]. Dropping it without sending, e.g. when the
+/// job panics, fails the reply.
+struct Responder<T> {
+    slot: Option<Arc<Mutex<Slot<T>>>>,
+}
+
+impl<T> Responder<T> {
+    fn send(mut self, result: Result<T, EchartsError>) {
+        if let Some(slot) = self.slot.take() {
+            fill(&slot, result);
+        }
+    }
+}
+
+impl<T> Drop for Responder<T> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            fill(
+                &slot,
+                Err(EchartsError::JsRuntimeError(
+                    "the render was aborted by its renderer thread".to_string(),
+                )),
+            );
+        }
+    }
+}
+
+fn fill<T>(slot: &Mutex<Slot<T>>, result: Result<T, EchartsError>) {
+    let mut slot = slot.lock().unwrap_or_else(|error| error.into_inner());
+    slot.result = Some(result);
+    if let Some(waker) = slot.waker.take() {
+        waker.wake();
+    }
+}
+
+/// Future resolving to the result of a job run by a worker
+struct Reply<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+}
+
+impl<T> Future for Reply<T> {
+    type Output = Result<T, EchartsError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.lock().unwrap_or_else(|error| error.into_inner());
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        task::Wake,
+        thread::{self, Thread},
+        time::Duration,
+    };
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// A renderer counting the jobs it ran, and its drops in `dropped`
+    struct Counter {
+        jobs: usize,
+        dropped: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Counter {
+        fn drop(&mut self) {
+            self.dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn counter_pool(workers: usize) -> (WorkerPool<Counter>, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let created = Arc::new(AtomicUsize::new(0));
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let pool = WorkerPool::new(workers, {
+            let created = Arc::clone(&created);
+            let dropped = Arc::clone(&dropped);
+            move || {
+                created.fetch_add(1, Ordering::SeqCst);
+                Counter {
+                    jobs: 0,
+                    dropped: Arc::clone(&dropped),
+                }
+            }
+        });
+        (pool, created, dropped)
+    }
+
+    #[test]
+    fn reply_resolves_with_the_sent_result() {
+        let (responder, later) = reply::<u32>();
+        let sender = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            responder.send(Ok(42));
+        });
+        assert_eq!(block_on(later).unwrap(), 42);
+        sender.join().unwrap();
+
+        let (responder, ready) = reply::<u32>();
+        responder.send(Err(EchartsError::JsRuntimeError("failed".to_string())));
+        assert!(matches!(
+            block_on(ready),
+            Err(EchartsError::JsRuntimeError(message)) if message == "failed"
+        ));
+    }
+
+    #[test]
+    fn dropped_responder_fails_the_reply() {
+        let (responder, reply) = reply::<u32>();
+        drop(responder);
+        assert!(matches!(
+            block_on(reply),
+            Err(EchartsError::JsRuntimeError(_))
+        ));
+    }
+
+    #[test]
+    fn jobs_run_on_warm_renderers() {
+        let (pool, created, _) = counter_pool(1);
+        for expected in 1..=3 {
+            let jobs = block_on(pool.submit(|counter: &mut Counter| {
+                counter.jobs += 1;
+                Ok(counter.jobs)
+            }));
+            assert_eq!(jobs.unwrap(), expected);
+        }
+        assert_eq!(created.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn drop_finishes_queued_jobs_and_joins_workers() {
+        let (pool, created, dropped) = counter_pool(2);
+        let done = Arc::new(AtomicUsize::new(0));
+        let replies: Vec<_> = (0..6)
+            .map(|_| {
+                let done = Arc::clone(&done);
+                pool.submit(move |_: &mut Counter| {
+                    thread::sleep(Duration::from_millis(10));
+                    done.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                })
+            })
+            .collect();
+
+        drop(pool);
+        assert_eq!(done.load(Ordering::SeqCst), 6);
+        assert_eq!(created.load(Ordering::SeqCst), 2);
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+        for reply in replies {
+            assert!(block_on(reply).is_ok());
+        }
+    }
+
+    #[test]
+    fn panicking_job_fails_its_reply_and_replaces_the_renderer() {
+        let (pool, created, dropped) = counter_pool(1);
+
+        let reply = pool
+            .submit(|_: &mut Counter| -> Result<(), EchartsError> { panic!("render panicked") });
+        assert!(matches!(
+            block_on(reply),
+            Err(EchartsError::JsRuntimeError(_))
+        ));
+
+        let jobs = block_on(pool.submit(|counter: &mut Counter| {
+            counter.jobs += 1;
+            Ok(counter.jobs)
+        }));
+        assert_eq!(jobs.unwrap(), 1);
+        assert_eq!(created.load(Ordering::SeqCst), 2);
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+    }
+}
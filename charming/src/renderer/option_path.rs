@@ -0,0 +1,161 @@
+//! Locate the option containing a position of a serialized chart, to point
+//! JavaScript errors at the `JsFunction` that threw them.
+
+enum Frame {
+    Object(Option<String>),
+    Array(usize),
+}
+
+/// Path of the option at byte `offset` of `option`, the pretty printed
+/// output of `Chart::to_string`, e.g. `series[0].label.formatter`.
+pub(crate) fn option_path_at(option: &str, offset: usize) -> Option<String> {
+    if offset >= option.len() {
+        return None;
+    }
+
+    let bytes = option.as_bytes();
+    let mut stack: Vec<Frame> = vec![];
+    let mut last_string: Option<String> = None;
+    let mut i = 0;
+
+    while i < offset {
+        match bytes[i] {
+            b'"' => {
+                let end = string_end(bytes, i, b'"');
+                last_string = serde_json::from_str(&option[i..end]).ok();
+                i = end;
+                continue;
+            }
+            b':' => {
+                if let Some(Frame::Object(key)) = stack.last_mut() {
+                    *key = last_string.take();
+                }
+            }
+            b',' => match stack.last_mut() {
+                Some(Frame::Array(index)) => *index += 1,
+                Some(Frame::Object(key)) => *key = None,
+                None => {}
+            },
+            b'{' => stack.push(Frame::Object(None)),
+            b'[' => stack.push(Frame::Array(0)),
+            b'}' | b']' => {
+                stack.pop();
+            }
+            b'f' if option[i..].starts_with("function") => {
+                // Function bodies are not JSON, skip them as a whole.
+                let end = function_end(bytes, i);
+                if offset < end {
+                    break;
+                }
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    let mut path = String::new();
+    for frame in &stack {
+        match frame {
+            Frame::Object(Some(key)) => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            Frame::Object(None) => break,
+            Frame::Array(index) => path.push_str(&format!("[{index}]")),
+        }
+    }
+    (!path.is_empty()).then_some(path)
+}
+
+/// Byte offset after the string starting with the quote at `start`
+fn string_end(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            c if c == quote => return i + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Byte offset after the body of the function starting at `start`
+fn function_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'"' | b'\'' | b'`') => {
+                i = string_end(bytes, i, quote);
+                continue;
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::option_path_at;
+
+    const OPTION: &str = r#"{
+  "title": {
+    "text": "a, b: {c}"
+  },
+  "series": [
+    {
+      "type": "bar",
+      "data": [1, 2]
+    },
+    {
+      "type": "line",
+      "label": {
+        "formatter": function(params) { if (params.value) { return "}"; } throw new Error('x'); }
+      }
+    }
+  ]
+}"#;
+
+    fn path_at(needle: &str) -> Option<String> {
+        option_path_at(OPTION, OPTION.find(needle).unwrap())
+    }
+
+    #[test]
+    fn paths_of_values() {
+        assert_eq!(path_at("\"a, b").as_deref(), Some("title.text"));
+        assert_eq!(path_at("\"bar\"").as_deref(), Some("series[0].type"));
+        assert_eq!(path_at("2]").as_deref(), Some("series[0].data[1]"));
+    }
+
+    #[test]
+    fn paths_inside_functions() {
+        assert_eq!(
+            path_at("throw").as_deref(),
+            Some("series[1].label.formatter")
+        );
+    }
+
+    #[test]
+    fn path_after_function() {
+        let option = r#"{ "a": [function() { return [1, 2]; }, { "b": 1 }] }"#;
+        assert_eq!(
+            option_path_at(option, option.find("\"b\"").unwrap() + 5).as_deref(),
+            Some("a[1].b")
+        );
+    }
+}
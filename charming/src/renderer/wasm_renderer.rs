@@ -1,8 +1,32 @@
-use crate::{element::Easing, theme::Theme, Chart, EchartsError};
-use serde::Serialize;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap, VecDeque},
+};
+
+use crate::{
+    action::Action,
+    datatype::{DataFrame, DataPoint},
+    element::Easing,
+    theme::Theme,
+    Chart, EchartsError,
+};
+use charming_macros::CharmingSetters;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use serde_wasm_bindgen::to_value;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast, JsValue};
+
+thread_local! {
+    /// The closures of the event handlers of each chart, by chart id, kept
+    /// alive until they are removed with `off` or the chart is disposed.
+    static EVENT_HANDLERS: RefCell<HashMap<String, Vec<EventHandler>>> =
+        RefCell::new(HashMap::new());
+}
+
+struct EventHandler {
+    event: String,
+    closure: Closure<dyn FnMut(JsValue)>,
+}
 
 pub struct WasmRenderer {
     theme: Theme,
@@ -45,9 +69,15 @@ impl WasmRenderer {
                 "no element with id `{}` found",
                 id
             )))?;
+        if let Theme::Definition(definition) = &self.theme {
+            let theme = definition
+                .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+                .map_err(|error| EchartsError::WasmError(error.to_string()))?;
+            register_theme(definition.name(), theme);
+        }
         let echarts = init(
             &element,
-            self.theme.to_str().0,
+            self.theme.name(),
             to_value(&ChartSize {
                 width: self.width,
                 height: self.height,
@@ -65,12 +95,413 @@ impl WasmRenderer {
             .resize(to_value(&chart_size).expect("could not convert resize options to `JsValue`"));
     }
 
+    /// Calls `handler` with the params of every `event` of the chart, e.g.
+    /// `"click"`, `"legendselectchanged"` or `"brushselected"`. The handler
+    /// is kept until it is removed with [`Self::off`] or the chart is
+    /// disposed with [`Self::dispose`].
+    ///
+    /// ```rust,no_run
+    /// use charming::{Chart, WasmRenderer};
+    ///
+    /// let echarts = WasmRenderer::new(600, 400).render("chart", &Chart::new()).unwrap();
+    /// WasmRenderer::on(&echarts, "click", |params| {
+    ///     if let (Some(series), Some(index)) = (params.series_index, params.data_index) {
+    ///         // show the details of the clicked data item
+    ///     }
+    /// });
+    /// ```
+    pub fn on<F>(echarts: &Echarts, event: &str, handler: F)
+    where
+        F: FnMut(EventParams) + 'static,
+    {
+        let closure = event_closure(handler);
+        echarts.on(event, closure.as_ref().unchecked_ref());
+        add_event_handler(echarts, event, closure);
+    }
+
+    /// Like [`Self::on`], for the events of the components matching `query`
+    /// only. The query is either a string like `"series.bar"`, or an object
+    /// like `serde_json::json!({ "seriesIndex": 1 })`.
+    pub fn on_query<Q, F>(
+        echarts: &Echarts,
+        event: &str,
+        query: &Q,
+        handler: F,
+    ) -> Result<(), EchartsError>
+    where
+        Q: Serialize + ?Sized,
+        F: FnMut(EventParams) + 'static,
+    {
+        let query = query
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|error| EchartsError::WasmError(error.to_string()))?;
+        let closure = event_closure(handler);
+        echarts.on_with_query(event, query, closure.as_ref().unchecked_ref());
+        add_event_handler(echarts, event, closure);
+        Ok(())
+    }
+
+    /// Removes the handlers of `event` registered with [`Self::on`] and
+    /// [`Self::on_query`].
+    pub fn off(echarts: &Echarts, event: &str) {
+        let removed = EVENT_HANDLERS.with(|handlers| {
+            let mut handlers = handlers.borrow_mut();
+            let Some(chart_handlers) = handlers.get_mut(&echarts.get_id()) else {
+                return vec![];
+            };
+            let (removed, kept) = std::mem::take(chart_handlers)
+                .into_iter()
+                .partition(|handler| handler.event == event);
+            *chart_handlers = kept;
+            removed
+        });
+        for handler in removed {
+            echarts.off(&handler.event, handler.closure.as_ref().unchecked_ref());
+        }
+    }
+
+    /// Disposes the chart and drops its event handlers.
+    pub fn dispose(echarts: &Echarts) {
+        let removed = EVENT_HANDLERS
+            .with(|handlers| handlers.borrow_mut().remove(&echarts.get_id()))
+            .unwrap_or_default();
+        for handler in &removed {
+            echarts.off(&handler.event, handler.closure.as_ref().unchecked_ref());
+        }
+        echarts.dispose();
+    }
+
+    /// Triggers an action of the chart, e.g. highlighting a series or
+    /// moving a data zoom window.
+    ///
+    /// ```rust,no_run
+    /// use charming::{action::{Action, ActionTarget}, Chart, WasmRenderer};
+    ///
+    /// let echarts = WasmRenderer::new(600, 400).render("chart", &Chart::new()).unwrap();
+    /// WasmRenderer::dispatch_action(
+    ///     &echarts,
+    ///     Action::Highlight(ActionTarget::new().series_name("Sales")),
+    /// );
+    /// ```
+    pub fn dispatch_action(echarts: &Echarts, action: Action) {
+        let action = action
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .expect("could not convert action to `JsValue`");
+        echarts.dispatch_action(action);
+    }
+
+    /// Registers the geo maps of the chart and sets the chart option
     pub fn update(echarts: &Echarts, chart: &Chart) {
+        Self::update_with(echarts, chart, &UpdateOptions::new());
+    }
+
+    /// Like [`Self::update`], with the merge and update behavior of
+    /// [`UpdateOptions`], e.g. to remove the series missing from `chart`.
+    ///
+    /// ```rust,no_run
+    /// use charming::{series::Line, Chart, UpdateOptions, WasmRenderer};
+    ///
+    /// let echarts = WasmRenderer::new(600, 400).render("chart", &Chart::new()).unwrap();
+    /// WasmRenderer::update_with(
+    ///     &echarts,
+    ///     &Chart::new().series(Line::new().data(vec![1, 2, 3])),
+    ///     &UpdateOptions::new().replace_merge("series"),
+    /// );
+    /// ```
+    pub fn update_with(echarts: &Echarts, chart: &Chart, options: &UpdateOptions) {
+        for (name, opt) in chart
+            .geo_maps()
+            .into_iter()
+            .filter_map(|geo_map| geo_map.register_map_args())
+        {
+            let opt = opt
+                .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+                .unwrap();
+            register_map(name, opt);
+        }
         let js = serde_wasm_bindgen::to_value(&chart).unwrap();
-        echarts.set_option(js);
+        echarts.set_option(js, update_options(options));
+    }
+
+    /// Appends data items to a series without setting the whole option
+    /// again. ECharts supports this for the scatter, lines and other large
+    /// scale series only; see [`LineBuffer`] for line series.
+    pub fn append_data<D: Into<DataPoint>>(echarts: &Echarts, series_index: usize, data: Vec<D>) {
+        let params = AppendData {
+            series_index,
+            data: data.into_iter().map(|d| d.into()).collect(),
+        };
+        echarts.append_data(
+            params
+                .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+                .expect("could not convert appended data to `JsValue`"),
+        );
     }
 }
 
+fn update_options(options: &UpdateOptions) -> JsValue {
+    options
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .expect("could not convert update options to `JsValue`")
+}
+
+fn event_closure<F>(mut handler: F) -> Closure<dyn FnMut(JsValue)>
+where
+    F: FnMut(EventParams) + 'static,
+{
+    Closure::new(
+        move |params: JsValue| match serde_wasm_bindgen::from_value(params) {
+            Ok(params) => handler(params),
+            Err(error) => wasm_bindgen::throw_str(&format!("invalid event params: {error}")),
+        },
+    )
+}
+
+fn add_event_handler(echarts: &Echarts, event: &str, closure: Closure<dyn FnMut(JsValue)>) {
+    EVENT_HANDLERS.with(|handlers| {
+        handlers
+            .borrow_mut()
+            .entry(echarts.get_id())
+            .or_default()
+            .push(EventHandler {
+                event: event.to_string(),
+                closure,
+            })
+    });
+}
+
+/// Options of [`WasmRenderer::update_with`], which control how the new
+/// option is merged into the current one.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOptions {
+    /// Replace the current option instead of merging the new one into it.
+    not_merge: Option<bool>,
+    /// Component types, e.g. `series` or `xAxis`, whose components are
+    /// replaced by those of the new option, so the ones it doesn't have are
+    /// removed, while the others are merged.
+    replace_merge: Vec<String>,
+    /// Update the chart in the next animation frame instead of right away.
+    lazy_update: Option<bool>,
+    /// Don't emit events for the update.
+    silent: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppendData {
+    series_index: usize,
+    data: DataFrame,
+}
+
+/// A ring buffer of the last points of a line series, for live data. Only
+/// the buffered points of the series are sent to the chart on
+/// [`Self::sync`], the rest of the option and the dropped points are not
+/// sent again. The x axis should be a time or value axis with `[x, y]`
+/// points, as the categories of a category axis are not shifted.
+///
+/// ```rust,no_run
+/// use charming::{component::Axis, element::AxisType, series::Line, Chart, LineBuffer, WasmRenderer};
+///
+/// let chart = Chart::new()
+///     .x_axis(Axis::new().type_(AxisType::Time))
+///     .y_axis(Axis::new())
+///     .series(Line::new().show_symbol(false));
+/// let echarts = WasmRenderer::new(600, 400).render("chart", &chart).unwrap();
+///
+/// let mut buffer = LineBuffer::new(0, 600);
+/// // on every new sample:
+/// buffer.push(vec![1_700_000_000_000_i64, 42]);
+/// buffer.sync(&echarts);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineBuffer {
+    series_index: usize,
+    capacity: usize,
+    points: VecDeque<DataPoint>,
+}
+
+impl LineBuffer {
+    /// Creates a buffer keeping the last `capacity` points of the series at
+    /// `series_index`.
+    pub fn new(series_index: usize, capacity: usize) -> Self {
+        Self {
+            series_index,
+            capacity,
+            points: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a point, dropping the oldest one when the buffer is full.
+    pub fn push<D: Into<DataPoint>>(&mut self, point: D) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.points.len() == self.capacity {
+            self.points.pop_front();
+        }
+        self.points.push_back(point.into());
+    }
+
+    /// Adds the points in order, keeping the last `capacity` of them.
+    pub fn extend<D: Into<DataPoint>>(&mut self, points: Vec<D>) {
+        for point in points {
+            self.push(point);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn series_index(&self) -> usize {
+        self.series_index
+    }
+
+    /// The buffered points, oldest first.
+    pub fn points(&self) -> impl Iterator<Item = &DataPoint> {
+        self.points.iter()
+    }
+
+    /// The option sent by [`Self::sync`], which sets the data of the series
+    /// and leaves the series before it as they are.
+    pub fn option(&self) -> serde_json::Value {
+        let mut series = vec![serde_json::json!({}); self.series_index];
+        series.push(serde_json::json!({ "data": self.points }));
+        serde_json::json!({ "series": series })
+    }
+
+    /// Sets the buffered points as the data of the series, in the next
+    /// animation frame.
+    pub fn sync(&self, echarts: &Echarts) {
+        let option = self
+            .option()
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .expect("could not convert line data to `JsValue`");
+        echarts.set_option(
+            option,
+            update_options(&UpdateOptions::new().lazy_update(true)),
+        );
+    }
+}
+
+/// Deserialize a field whose shape varies between events and ECharts
+/// versions, falling back to the default instead of failing the whole event
+fn lenient<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + Default,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).unwrap_or_default())
+}
+
+/// The params of a chart event, passed to the handlers of
+/// [`WasmRenderer::on`]. Which fields are set depends on the event.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventParams {
+    /// The event type, e.g. `click` or `legendselectchanged`
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    /// Type of the component the event comes from, e.g. `series` or
+    /// `markPoint`
+    pub component_type: Option<String>,
+    pub component_index: Option<usize>,
+    /// Type of the series the event comes from, e.g. `bar` or `line`
+    pub series_type: Option<String>,
+    pub series_index: Option<usize>,
+    pub series_name: Option<String>,
+    pub data_index: Option<usize>,
+    /// Type of the data item in graph series, `node` or `edge`
+    pub data_type: Option<String>,
+    /// Name of the data item, or of the legend item of legend events
+    pub name: Option<String>,
+    /// Value of the data item
+    pub value: Option<serde_json::Value>,
+    /// The data item
+    pub data: Option<serde_json::Value>,
+    /// Selection state of legend and data selection events, `None` if the
+    /// event has a selection state of another shape
+    #[serde(deserialize_with = "lenient")]
+    pub selected: Option<Selected>,
+    /// The payloads of brush and data zoom events, empty if the event has
+    /// payloads of another shape
+    #[serde(deserialize_with = "lenient")]
+    pub batch: Vec<EventBatch>,
+    /// Start of the window of data zoom events, in percent
+    pub start: Option<f64>,
+    /// End of the window of data zoom events, in percent
+    pub end: Option<f64>,
+}
+
+/// Selection state of an event, see [`EventParams::selected`]
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Selected {
+    /// Whether each legend item is selected, by name, for legend events
+    Legend(BTreeMap<String, bool>),
+    /// The selected data items of each series, for `selectchanged` events
+    Series(Vec<SeriesSelection>),
+}
+
+/// The selected data items of a series
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SeriesSelection {
+    pub series_index: usize,
+    pub series_name: Option<String>,
+    pub data_index: Vec<usize>,
+}
+
+/// A payload of a brush or data zoom event, see [`EventParams::batch`]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventBatch {
+    pub brush_id: Option<String>,
+    pub brush_index: Option<usize>,
+    pub brush_name: Option<String>,
+    /// The brushed areas
+    pub areas: Vec<BrushArea>,
+    /// The data items selected by the brush, of each series
+    pub selected: Vec<SeriesSelection>,
+    pub data_zoom_id: Option<String>,
+    /// Start of the data zoom window, in percent
+    pub start: Option<f64>,
+    /// End of the data zoom window, in percent
+    pub end: Option<f64>,
+    pub start_value: Option<serde_json::Value>,
+    pub end_value: Option<serde_json::Value>,
+}
+
+/// An area selected by a brush
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BrushArea {
+    /// The brush type, e.g. `rect` or `lineX`
+    pub brush_type: Option<String>,
+    /// The area in pixels
+    pub range: Option<serde_json::Value>,
+    /// The area in the coordinates of the coordinate system
+    pub coord_range: Option<serde_json::Value>,
+}
+
 #[derive(Clone, Debug, Serialize, Copy)]
 struct ChartSize {
     width: Option<u32>,
@@ -136,9 +567,36 @@ extern "C" {
     #[wasm_bindgen(js_namespace = echarts, js_name = init)]
     fn init(id: &web_sys::Element, theme: &str, size: JsValue) -> Echarts;
 
+    #[wasm_bindgen(js_namespace = echarts, js_name = registerTheme)]
+    fn register_theme(name: &str, theme: JsValue);
+
+    #[wasm_bindgen(js_namespace = echarts, js_name = registerMap)]
+    fn register_map(name: &str, opt: JsValue);
+
     #[wasm_bindgen(method, js_name = "setOption")]
-    fn set_option(this: &Echarts, option: JsValue);
+    fn set_option(this: &Echarts, option: JsValue, opts: JsValue);
+
+    #[wasm_bindgen(method, js_name = "appendData")]
+    fn append_data(this: &Echarts, params: JsValue);
 
     #[wasm_bindgen(method, js_name = "resize")]
     pub fn resize(this: &Echarts, opts: JsValue);
+
+    #[wasm_bindgen(method, js_name = "getId")]
+    fn get_id(this: &Echarts) -> String;
+
+    #[wasm_bindgen(method, js_name = "on")]
+    fn on(this: &Echarts, event: &str, handler: &js_sys::Function);
+
+    #[wasm_bindgen(method, js_name = "on")]
+    fn on_with_query(this: &Echarts, event: &str, query: JsValue, handler: &js_sys::Function);
+
+    #[wasm_bindgen(method, js_name = "off")]
+    fn off(this: &Echarts, event: &str, handler: &js_sys::Function);
+
+    #[wasm_bindgen(method, js_name = "dispose")]
+    fn dispose(this: &Echarts);
+
+    #[wasm_bindgen(method, js_name = "dispatchAction")]
+    fn dispatch_action(this: &Echarts, action: JsValue);
 }
@@ -10,13 +10,16 @@ use serde::{Deserialize, Serialize};
   Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
 )]
 #[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Bar3d {
     #[serde(rename = "type")]
     #[charming_type = "bar3D"]
     type_: String,
     name: Option<String>,
     coordinate_system: Option<CoordinateSystem>,
+    #[serde(rename = "grid3DIndex")]
     grid3d_index: Option<CompositeValue>,
+    #[serde(rename = "geo3DIndex")]
     geo3d_index: Option<CompositeValue>,
     globe_index: Option<CompositeValue>,
     shading: Option<String>,
@@ -0,0 +1,33 @@
+use crate::{
+    datatype::{CompositeValue, DataFrame, DataPoint},
+    element::{CoordinateSystem, DimensionEncode, Emphasis, LineStyle},
+};
+use charming_macros::CharmingSetters;
+use serde::{Deserialize, Serialize};
+
+/// 3D line series. It needs the echarts-gl extension.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Line3d {
+    #[serde(rename = "type")]
+    #[charming_type = "line3D"]
+    type_: String,
+    name: Option<String>,
+    coordinate_system: Option<CoordinateSystem>,
+    #[serde(rename = "grid3DIndex")]
+    grid3d_index: Option<CompositeValue>,
+    line_style: Option<LineStyle>,
+    emphasis: Option<Emphasis>,
+    /// The `zlevel` value of all graphical elements in the series.
+    zlevel: Option<f64>,
+    silent: Option<bool>,
+    animation: Option<bool>,
+    dataset_index: Option<f64>,
+    encode: Option<DimensionEncode>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    data: DataFrame,
+}
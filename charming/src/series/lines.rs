@@ -1,11 +1,80 @@
-#![allow(dead_code)]
-
-use crate::element::{
-    ColorBy, CoordinateSystem, Emphasis, Label, LabelLayout, LineStyle, Symbol, Tooltip,
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        Color, ColorBy, CoordinateSystem, Emphasis, Label, LabelLayout, LineStyle, Symbol, Tooltip,
+    },
 };
 use charming_macros::CharmingSetters;
 use serde::{Deserialize, Serialize};
 
+/// Trail animation moving along the lines.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LinesEffect {
+    show: Option<bool>,
+    /// Duration of one pass of the effect, in seconds.
+    period: Option<f64>,
+    /// Delay before the effect starts, in milliseconds.
+    delay: Option<f64>,
+    /// Speed of the effect in pixels per second. Overrides `period`.
+    constant_speed: Option<f64>,
+    symbol: Option<Symbol>,
+    symbol_size: Option<f64>,
+    color: Option<Color>,
+    /// Length of the trail, from 0 to 1.
+    trail_length: Option<f64>,
+    #[serde(rename = "loop")]
+    loop_: Option<bool>,
+    /// Whether to go back and forth along the line.
+    round_trip: Option<bool>,
+}
+
+/// A single line of a [`Lines`] series.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LinesData {
+    name: Option<String>,
+    value: Option<CompositeValue>,
+    /// Points of the line. A line has exactly two points unless the series
+    /// is a polyline.
+    #[charming_skip_setter]
+    coords: Vec<CompositeValue>,
+    line_style: Option<LineStyle>,
+    label: Option<Label>,
+    emphasis: Option<Emphasis>,
+}
+
+impl LinesData {
+    pub fn coords<X: Into<CompositeValue>, Y: Into<CompositeValue>>(
+        mut self,
+        coords: Vec<(X, Y)>,
+    ) -> Self {
+        self.coords = coords
+            .into_iter()
+            .map(|(x, y)| CompositeValue::Array(vec![x.into(), y.into()]))
+            .collect();
+        self
+    }
+}
+
+impl<X, Y> From<Vec<(X, Y)>> for LinesData
+where
+    X: Into<CompositeValue>,
+    Y: Into<CompositeValue>,
+{
+    fn from(coords: Vec<(X, Y)>) -> Self {
+        LinesData::new().coords(coords)
+    }
+}
+
 #[serde_with::apply(
   Option => #[serde(skip_serializing_if = "Option::is_none")],
   Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -24,6 +93,7 @@ pub struct Lines {
     y_axis_index: Option<f64>,
     geo_index: Option<f64>,
     polyline: Option<bool>,
+    effect: Option<LinesEffect>,
     large: Option<bool>,
     large_threshold: Option<f64>,
     symbol: Option<Symbol>,
@@ -32,5 +102,12 @@ pub struct Lines {
     label: Option<Label>,
     label_layout: Option<LabelLayout>,
     emphasis: Option<Emphasis>,
+    /// The `zlevel` value of all graphical elements in the series.
+    zlevel: Option<f64>,
+    /// The `z` value of all graphical elements in the series.
+    z: Option<f64>,
+    silent: Option<bool>,
     tooltip: Option<Tooltip>,
+    #[charming_set_vec]
+    data: Vec<LinesData>,
 }
@@ -1,5 +1,104 @@
+use crate::{
+    datatype::{CompositeValue, DataFrame, DataPoint},
+    element::{
+        Blur, Emphasis, ItemStyle, Label, LabelLayout, MarkArea, MarkLine, MarkPoint, ScaleLimit,
+        Select, SeriesLayoutBy, Tooltip,
+    },
+};
+use charming_macros::CharmingSetters;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum MapValueCalculation {
+    Sum,
+    Average,
+    Max,
+    Min,
+}
+
+/// Map series, mainly used for geographical area visualization such as
+/// choropleth charts. The map data must be registered with
+/// [`Chart::geo_map`](crate::Chart::geo_map) under the name given to
+/// [`Map::map`].
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Map {}
+pub struct Map {
+    #[serde(rename = "type")]
+    #[charming_type = "map"]
+    type_: String,
+    id: Option<String>,
+    name: Option<String>,
+    /// Name of the registered map.
+    map: Option<String>,
+    /// Whether to enable mouse zooming and translating.
+    roam: Option<bool>,
+    #[charming_skip_setter]
+    center: Option<(f64, f64)>,
+    aspect_scale: Option<f64>,
+    zoom: Option<f64>,
+    scale_limit: Option<ScaleLimit>,
+    #[charming_skip_setter]
+    name_map: Option<BTreeMap<String, String>>,
+    name_property: Option<String>,
+    selected_mode: Option<bool>,
+    label: Option<Label>,
+    item_style: Option<ItemStyle>,
+    emphasis: Option<Emphasis>,
+    select: Option<Select>,
+    blur: Option<Blur>,
+    /// The `zlevel` value of all graphical elements in the map.
+    zlevel: Option<f64>,
+    /// The `z` value of all graphical elements in the map.
+    z: Option<f64>,
+    left: Option<CompositeValue>,
+    top: Option<CompositeValue>,
+    right: Option<CompositeValue>,
+    bottom: Option<CompositeValue>,
+    #[charming_skip_setter]
+    layout_center: Option<(String, String)>,
+    layout_size: Option<CompositeValue>,
+    /// Index of the geo component to share, instead of creating a new one.
+    geo_index: Option<f64>,
+    /// How to combine the values of several map series sharing the same map.
+    map_value_calculation: Option<MapValueCalculation>,
+    show_legend_symbol: Option<bool>,
+    series_layout_by: Option<SeriesLayoutBy>,
+    dataset_index: Option<f64>,
+    label_layout: Option<LabelLayout>,
+    mark_point: Option<MarkPoint>,
+    mark_line: Option<MarkLine>,
+    mark_area: Option<MarkArea>,
+    silent: Option<bool>,
+    tooltip: Option<Tooltip>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    data: DataFrame,
+}
+
+impl Map {
+    pub fn center<F: Into<f64>>(mut self, center: (F, F)) -> Self {
+        self.center = Some((center.0.into(), center.1.into()));
+        self
+    }
+
+    /// Maps the names of the regions in the map data to custom names.
+    pub fn name_map<S: Into<String>>(mut self, name_map: Vec<(S, S)>) -> Self {
+        self.name_map = Some(
+            name_map
+                .into_iter()
+                .map(|(from, to)| (from.into(), to.into()))
+                .collect(),
+        );
+        self
+    }
+
+    pub fn layout_center<S: Into<String>>(mut self, layout_center: (S, S)) -> Self {
+        self.layout_center = Some((layout_center.0.into(), layout_center.1.into()));
+        self
+    }
+}
@@ -13,6 +13,7 @@ pub mod gauge;
 pub mod graph;
 pub mod heatmap;
 pub mod line;
+pub mod line3d;
 pub mod lines;
 pub mod map;
 pub mod parallel;
@@ -21,7 +22,9 @@ pub mod pie;
 pub mod radar;
 pub mod sankey;
 pub mod scatter;
+pub mod scatter3d;
 pub mod sunburst;
+pub mod surface;
 pub mod theme_river;
 pub mod tree;
 pub mod treemap;
@@ -37,6 +40,7 @@ pub use gauge::*;
 pub use graph::*;
 pub use heatmap::*;
 pub use line::*;
+pub use line3d::*;
 pub use lines::*;
 pub use map::*;
 pub use parallel::*;
@@ -45,7 +49,9 @@ pub use pie::*;
 pub use radar::*;
 pub use sankey::*;
 pub use scatter::*;
+pub use scatter3d::*;
 pub use sunburst::*;
+pub use surface::*;
 pub use theme_river::*;
 pub use tree::*;
 pub use treemap::*;
@@ -63,6 +69,8 @@ pub enum Series {
     Graph(graph::Graph),
     Heatmap(heatmap::Heatmap),
     Line(line::Line),
+    Line3d(line3d::Line3d),
+    Lines(lines::Lines),
     Map(map::Map),
     Parallel(parallel::Parallel),
     PictorialBar(pictorial_bar::PictorialBar),
@@ -70,7 +78,9 @@ pub enum Series {
     Radar(radar::Radar),
     Sankey(sankey::Sankey),
     Scatter(scatter::Scatter),
+    Scatter3d(scatter3d::Scatter3d),
     Sunburst(sunburst::Sunburst),
+    Surface(surface::Surface),
     ThemeRiver(theme_river::ThemeRiver),
     Tree(tree::Tree),
     Treemap(treemap::Treemap),
@@ -118,6 +128,8 @@ impl_series_deserialize!(
     Graph => "graph",
     Heatmap => "heatmap",
     Line => "line",
+    Line3d => "line3D",
+    Lines => "lines",
     Map => "map",
     Parallel => "parallel",
     PictorialBar => "pictorialBar",
@@ -125,7 +137,9 @@ impl_series_deserialize!(
     Radar => "radar",
     Sankey => "sankey",
     Scatter => "scatter",
+    Scatter3d => "scatter3D",
     Sunburst => "sunburst",
+    Surface => "surface",
     ThemeRiver => "themeRiver",
     Tree => "tree",
     Treemap => "treemap",
@@ -165,6 +179,8 @@ impl_series!(
     Graph,
     Heatmap,
     Line,
+    Line3d,
+    Lines,
     Map,
     Parallel,
     PictorialBar,
@@ -172,7 +188,9 @@ impl_series!(
     Radar,
     Sankey,
     Scatter,
+    Scatter3d,
     Sunburst,
+    Surface,
     ThemeRiver,
     Tree,
     Treemap
@@ -0,0 +1,41 @@
+use crate::{
+    datatype::{CompositeValue, DataFrame, DataPoint},
+    element::{CoordinateSystem, DimensionEncode, Emphasis, ItemStyle, Label, Symbol},
+};
+use charming_macros::CharmingSetters;
+use serde::{Deserialize, Serialize};
+
+/// 3D scatter series. It needs the echarts-gl extension.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Scatter3d {
+    #[serde(rename = "type")]
+    #[charming_type = "scatter3D"]
+    type_: String,
+    name: Option<String>,
+    coordinate_system: Option<CoordinateSystem>,
+    #[serde(rename = "grid3DIndex")]
+    grid3d_index: Option<CompositeValue>,
+    #[serde(rename = "geo3DIndex")]
+    geo3d_index: Option<CompositeValue>,
+    globe_index: Option<CompositeValue>,
+    symbol: Option<Symbol>,
+    symbol_size: Option<f64>,
+    item_style: Option<ItemStyle>,
+    label: Option<Label>,
+    emphasis: Option<Emphasis>,
+    /// How the colors of overlapping symbols are blended, e.g. `"lighter"`.
+    blend_mode: Option<String>,
+    /// The `zlevel` value of all graphical elements in the series.
+    zlevel: Option<f64>,
+    silent: Option<bool>,
+    animation: Option<bool>,
+    dataset_index: Option<f64>,
+    encode: Option<DimensionEncode>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    data: DataFrame,
+}
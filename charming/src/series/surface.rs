@@ -0,0 +1,103 @@
+use crate::{
+    datatype::{CompositeValue, DataFrame, DataPoint},
+    element::{CoordinateSystem, Emphasis, ItemStyle, JsFunction, LineStyle},
+};
+use charming_macros::CharmingSetters;
+use serde::{Deserialize, Serialize};
+
+/// Range and sampling step of a variable of a surface equation.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SurfaceRange {
+    step: Option<f64>,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+/// A surface given as `z = f(x, y)`.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SurfaceEquation {
+    x: Option<SurfaceRange>,
+    y: Option<SurfaceRange>,
+    /// Function of `(x, y)` returning the z value.
+    z: Option<JsFunction>,
+}
+
+/// A surface given as `x = f(u, v)`, `y = g(u, v)` and `z = h(u, v)`.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ParametricEquation {
+    u: Option<SurfaceRange>,
+    v: Option<SurfaceRange>,
+    x: Option<JsFunction>,
+    y: Option<JsFunction>,
+    z: Option<JsFunction>,
+}
+
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Wireframe {
+    show: Option<bool>,
+    line_style: Option<LineStyle>,
+}
+
+/// 3D surface series. The surface is either given by its data, by an
+/// `equation` or by a `parametric_equation`. It needs the echarts-gl
+/// extension.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Surface {
+    #[serde(rename = "type")]
+    #[charming_type = "surface"]
+    type_: String,
+    name: Option<String>,
+    coordinate_system: Option<CoordinateSystem>,
+    #[serde(rename = "grid3DIndex")]
+    grid3d_index: Option<CompositeValue>,
+    /// Whether the surface is given by a `parametric_equation`.
+    parametric: Option<bool>,
+    wireframe: Option<Wireframe>,
+    equation: Option<SurfaceEquation>,
+    parametric_equation: Option<ParametricEquation>,
+    /// Shading of the surface, `"color"`, `"lambert"` or `"realistic"`.
+    shading: Option<String>,
+    item_style: Option<ItemStyle>,
+    emphasis: Option<Emphasis>,
+    /// Number of rows and columns of the data grid.
+    #[charming_skip_setter]
+    data_shape: Option<(u64, u64)>,
+    /// The `zlevel` value of all graphical elements in the series.
+    zlevel: Option<f64>,
+    silent: Option<bool>,
+    animation: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    data: DataFrame,
+}
+
+impl Surface {
+    pub fn data_shape(mut self, data_shape: (u64, u64)) -> Self {
+        self.data_shape = Some(data_shape);
+        self
+    }
+}
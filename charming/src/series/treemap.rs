@@ -1,9 +1,264 @@
 use crate::{
     datatype::CompositeValue,
-    element::{Emphasis, ItemStyle, Label, Tooltip},
+    element::{Color, Emphasis, ItemStyle, Label, Tooltip},
 };
 use charming_macros::CharmingSetters;
-use serde::{Deserialize, Serialize};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TreemapColorMappingBy {
+    /// Map the value of the node to a color.
+    Value,
+    /// Map the index of the node among its siblings to a color.
+    Index,
+    /// Map the id of the node to a color, so the color stays the same when
+    /// the data changes.
+    Id,
+}
+
+/// Behavior when clicking a node.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum TreemapNodeClick {
+    /// Zoom to the clicked node.
+    ZoomToNode,
+    /// Open the `link` of the clicked node.
+    Link,
+    /// Do nothing.
+    None,
+}
+
+impl Serialize for TreemapNodeClick {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TreemapNodeClick::ZoomToNode => serializer.serialize_str("zoomToNode"),
+            TreemapNodeClick::Link => serializer.serialize_str("link"),
+            TreemapNodeClick::None => serializer.serialize_bool(false),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TreemapNodeClick {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TreemapNodeClickVisitor;
+
+        impl Visitor<'_> for TreemapNodeClickVisitor {
+            type Value = TreemapNodeClick;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(r#""zoomToNode", "link", or false"#)
+            }
+
+            fn visit_bool<E: de::Error>(self, value: bool) -> Result<TreemapNodeClick, E> {
+                match value {
+                    false => Ok(TreemapNodeClick::None),
+                    true => Err(de::Error::invalid_value(de::Unexpected::Bool(value), &self)),
+                }
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<TreemapNodeClick, E> {
+                match value {
+                    "zoomToNode" => Ok(TreemapNodeClick::ZoomToNode),
+                    "link" => Ok(TreemapNodeClick::Link),
+                    _ => Err(de::Error::unknown_variant(value, &["zoomToNode", "link"])),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(TreemapNodeClickVisitor)
+    }
+}
+
+/// Item style of treemap nodes, which additionally controls the gaps and
+/// borders between nested nodes.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TreemapItemStyle {
+    color: Option<Color>,
+    color_alpha: Option<f64>,
+    color_saturation: Option<f64>,
+    border_width: Option<f64>,
+    /// Gap between the children of a node.
+    gap_width: Option<f64>,
+    border_color: Option<Color>,
+    /// Saturation of the border color, derived from the color of the node.
+    border_color_saturation: Option<f64>,
+    border_radius: Option<f64>,
+    shadow_color: Option<Color>,
+    shadow_blur: Option<f64>,
+    shadow_offset_x: Option<f64>,
+    shadow_offset_y: Option<f64>,
+}
+
+/// The navigation bar showing the path from the root to the current node.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TreemapBreadcrumb {
+    show: Option<bool>,
+    left: Option<CompositeValue>,
+    top: Option<CompositeValue>,
+    right: Option<CompositeValue>,
+    bottom: Option<CompositeValue>,
+    height: Option<f64>,
+    empty_item_width: Option<f64>,
+    item_style: Option<ItemStyle>,
+}
+
+/// Configuration of a depth of the tree. The first level applies to the
+/// root, the second one to its children, and so on.
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, CharmingSetters, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TreemapLevel {
+    visual_dimension: Option<f64>,
+    visual_min: Option<f64>,
+    visual_max: Option<f64>,
+    color_mapping_by: Option<TreemapColorMappingBy>,
+    /// Colors of the nodes at this level.
+    #[charming_set_vec]
+    color: Vec<Color>,
+    #[charming_skip_setter]
+    color_alpha: Option<(f64, f64)>,
+    #[charming_skip_setter]
+    color_saturation: Option<(f64, f64)>,
+    /// Nodes with an area smaller than this are hidden.
+    visible_min: Option<f64>,
+    /// Children of nodes with an area smaller than this are hidden.
+    children_visible_min: Option<f64>,
+    item_style: Option<TreemapItemStyle>,
+    label: Option<Label>,
+    upper_label: Option<Label>,
+    emphasis: Option<Emphasis>,
+}
+
+impl TreemapLevel {
+    pub fn color_alpha<F: Into<f64>>(mut self, color_alpha: (F, F)) -> Self {
+        self.color_alpha = Some((color_alpha.0.into(), color_alpha.1.into()));
+        self
+    }
+
+    pub fn color_saturation<F: Into<f64>>(mut self, color_saturation: (F, F)) -> Self {
+        self.color_saturation = Some((color_saturation.0.into(), color_saturation.1.into()));
+        self
+    }
+}
+
+#[serde_with::apply(
+  Option => #[serde(skip_serializing_if = "Option::is_none")],
+  Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")]
+)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TreemapNode {
+    name: String,
+    id: Option<String>,
+    value: Option<CompositeValue>,
+    link: Option<String>,
+    color_mapping_by: Option<TreemapColorMappingBy>,
+    color: Vec<Color>,
+    item_style: Option<TreemapItemStyle>,
+    label: Option<Label>,
+    upper_label: Option<Label>,
+    children: Vec<TreemapNode>,
+}
+
+impl TreemapNode {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            id: None,
+            value: None,
+            link: None,
+            color_mapping_by: None,
+            color: vec![],
+            item_style: None,
+            label: None,
+            upper_label: None,
+            children: vec![],
+        }
+    }
+
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// The value of the node. An array value can be used together with
+    /// `visual_dimension` to map another dimension to the color.
+    pub fn value<V: Into<CompositeValue>>(mut self, value: V) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn link<S: Into<String>>(mut self, link: S) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    pub fn color_mapping_by(mut self, color_mapping_by: TreemapColorMappingBy) -> Self {
+        self.color_mapping_by = Some(color_mapping_by);
+        self
+    }
+
+    /// Colors of the children of this node.
+    pub fn color<C: Into<Color>>(mut self, color: Vec<C>) -> Self {
+        self.color = color.into_iter().map(|c| c.into()).collect();
+        self
+    }
+
+    pub fn item_style(mut self, item_style: TreemapItemStyle) -> Self {
+        self.item_style = Some(item_style);
+        self
+    }
+
+    pub fn label(mut self, label: Label) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn upper_label(mut self, upper_label: Label) -> Self {
+        self.upper_label = Some(upper_label);
+        self
+    }
+
+    pub fn children(mut self, children: Vec<TreemapNode>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+impl From<&str> for TreemapNode {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<(&str, f64)> for TreemapNode {
+    fn from((name, value): (&str, f64)) -> Self {
+        Self::new(name).value(value)
+    }
+}
+
+impl From<(&str, f64, &str)> for TreemapNode {
+    fn from((name, value, color): (&str, f64, &str)) -> Self {
+        Self::new(name)
+            .value(value)
+            .item_style(TreemapItemStyle::new().color(color))
+    }
+}
 
 #[serde_with::apply(
   Option => #[serde(skip_serializing_if = "Option::is_none")],
@@ -25,8 +280,47 @@ pub struct Treemap {
     bottom: Option<CompositeValue>,
     width: Option<CompositeValue>,
     height: Option<CompositeValue>,
+    /// The expected aspect ratio of the rectangles.
+    squared_ratio: Option<f64>,
+    /// Number of levels shown at once. Deeper levels are reached by drilling
+    /// down.
+    leaf_depth: Option<f64>,
+    /// Marker shown on nodes that can be drilled down.
+    drill_down_icon: Option<String>,
+    /// Whether to enable mouse zooming and translating.
+    roam: Option<bool>,
+    node_click: Option<TreemapNodeClick>,
+    zoom_to_node_ratio: Option<f64>,
+    visual_dimension: Option<f64>,
+    visual_min: Option<f64>,
+    visual_max: Option<f64>,
+    color_mapping_by: Option<TreemapColorMappingBy>,
+    #[charming_skip_setter]
+    color_alpha: Option<(f64, f64)>,
+    #[charming_skip_setter]
+    color_saturation: Option<(f64, f64)>,
+    visible_min: Option<f64>,
+    children_visible_min: Option<f64>,
     label: Option<Label>,
-    item_style: Option<ItemStyle>,
+    upper_label: Option<Label>,
+    item_style: Option<TreemapItemStyle>,
     emphasis: Option<Emphasis>,
+    breadcrumb: Option<TreemapBreadcrumb>,
+    #[charming_set_vec]
+    levels: Vec<TreemapLevel>,
     tooltip: Option<Tooltip>,
+    #[charming_set_vec]
+    data: Vec<TreemapNode>,
+}
+
+impl Treemap {
+    pub fn color_alpha<F: Into<f64>>(mut self, color_alpha: (F, F)) -> Self {
+        self.color_alpha = Some((color_alpha.0.into(), color_alpha.1.into()));
+        self
+    }
+
+    pub fn color_saturation<F: Into<f64>>(mut self, color_saturation: (F, F)) -> Self {
+        self.color_saturation = Some((color_saturation.0.into(), color_saturation.1.into()));
+        self
+    }
 }
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "html")]
+use crate::element::process_raw_strings_for_script;
+use crate::{
+    element::{Color, TextStyle},
+    EchartsError,
+};
+
+/// A theme built at runtime, registered with `echarts.registerTheme` by the
+/// renderers.
+///
+/// Besides the color palette, the background color and the global text
+/// style, a theme holds default options for series and components, keyed by
+/// the names ECharts uses in theme files, e.g. `"line"`, `"categoryAxis"` or
+/// `"legend"`.
+///
+/// ```rust
+/// use charming::{element::LineStyle, series::Line, theme::{Theme, ThemeDefinition}};
+///
+/// let theme: Theme = ThemeDefinition::new("brand")
+///     .color(vec!["#0b3c5d", "#328cc1", "#d9b310"])
+///     .background_color("#fafafa")
+///     .defaults("line", Line::new().line_style(LineStyle::new().width(3)))?
+///     .into();
+/// # Ok::<(), charming::EchartsError>(())
+/// ```
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeDefinition {
+    #[serde(skip)]
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    color: Vec<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text_style: Option<TextStyle>,
+    #[serde(flatten)]
+    defaults: BTreeMap<String, serde_json::Value>,
+}
+
+impl ThemeDefinition {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            color: vec![],
+            background_color: None,
+            text_style: None,
+            defaults: BTreeMap::new(),
+        }
+    }
+
+    /// Load a theme from the JSON of an ECharts theme file, either a plain
+    /// theme object or the `{ "themeName": ..., "theme": ... }` object
+    /// exported by the ECharts theme builder.
+    pub fn from_json<S: Into<String>>(name: S, json: &str) -> Result<Self, EchartsError> {
+        let mut value: serde_json::Value = serde_json::from_str(json)
+            .map_err(|error| EchartsError::ThemeLoadingError(error.to_string()))?;
+        if let Some(theme) = value.get_mut("theme").filter(|theme| theme.is_object()) {
+            value = theme.take();
+        }
+        let definition: Self = serde_json::from_value(value)
+            .map_err(|error| EchartsError::ThemeLoadingError(error.to_string()))?;
+        Ok(Self {
+            name: name.into(),
+            ..definition
+        })
+    }
+
+    /// Load a theme from an ECharts theme JSON file, see [`Self::from_json`].
+    pub fn from_file<S: Into<String>, P: AsRef<std::path::Path>>(
+        name: S,
+        path: P,
+    ) -> Result<Self, EchartsError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|error| EchartsError::ThemeLoadingError(error.to_string()))?;
+        Self::from_json(name, &json)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn color<C: Into<Color>>(mut self, color: Vec<C>) -> Self {
+        self.color = color.into_iter().map(|c| c.into()).collect();
+        self
+    }
+
+    pub fn background_color<C: Into<Color>>(mut self, background_color: C) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+
+    pub fn text_style<T: Into<TextStyle>>(mut self, text_style: T) -> Self {
+        self.text_style = Some(text_style.into());
+        self
+    }
+
+    /// Set the default options of a series or component type. The `type`
+    /// field of the defaults, if any, is dropped. Returns an error if the
+    /// defaults cannot be converted to JSON.
+    pub fn defaults<K: Into<String>, T: Serialize>(
+        mut self,
+        key: K,
+        defaults: T,
+    ) -> Result<Self, EchartsError> {
+        let mut value = serde_json::to_value(defaults)
+            .map_err(|error| EchartsError::ThemeLoadingError(error.to_string()))?;
+        if let Some(object) = value.as_object_mut() {
+            object.remove("type");
+        }
+        self.defaults.insert(key.into(), value);
+        Ok(self)
+    }
+
+    /// The script registering this theme with ECharts, with the `JsFunction`s
+    /// of the defaults as functions.
+    #[cfg(feature = "html")]
+    pub(crate) fn register_theme_script(&self) -> Result<String, EchartsError> {
+        let theme = serde_json::to_string(self)
+            .map_err(|error| EchartsError::ThemeLoadingError(error.to_string()))?;
+        Ok(process_raw_strings_for_script(&format!(
+            "echarts.registerTheme({}, {theme});",
+            serde_json::Value::from(self.name.as_str()),
+        )))
+    }
+}
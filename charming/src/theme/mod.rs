@@ -1,4 +1,13 @@
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg(feature = "html")]
+use crate::EchartsError;
+#[cfg(feature = "html")]
+use std::borrow::Cow;
+
+mod definition;
+
+pub use definition::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum Theme {
     #[default]
     Default,
@@ -16,31 +25,72 @@ pub enum Theme {
     PurplePassion,
     Halloween,
     Custom(&'static str, &'static str),
+    /// A theme built at runtime, see [`ThemeDefinition`].
+    Definition(Box<ThemeDefinition>),
+}
+
+impl From<ThemeDefinition> for Theme {
+    fn from(definition: ThemeDefinition) -> Self {
+        Theme::Definition(Box::new(definition))
+    }
 }
 
 impl Theme {
-    pub(crate) fn to_str(&self) -> (&'static str, &'static str) {
+    /// The name the theme is registered under, empty for the default theme.
+    pub(crate) fn name(&self) -> &str {
         match self {
-            Theme::Default => ("", ""),
-            Theme::Dark => ("dark", ""),
-            Theme::Vintage => ("vintage", include_str!("../asset/theme-vintage.js")),
-            Theme::Westeros => ("westeros", include_str!("../asset/theme-westeros.js")),
-            Theme::Essos => ("essos", include_str!("../asset/theme-essos.js")),
-            Theme::Wonderland => ("wonderland", include_str!("../asset/theme-wonderland.js")),
-            Theme::Walden => ("walden", include_str!("../asset/theme-walden.js")),
-            Theme::Chalk => ("chalk", include_str!("../asset/theme-chalk.js")),
-            Theme::Infographic => ("infographic", include_str!("../asset/theme-infographic.js")),
-            Theme::Macarons => ("macarons", include_str!("../asset/theme-macarons.js")),
-            Theme::Roma => ("roma", include_str!("../asset/theme-roma.js")),
-            Theme::Shine => ("shine", include_str!("../asset/theme-shine.js")),
-            Theme::PurplePassion => (
-                "purple-passion",
-                include_str!("../asset/theme-purple-passion.js"),
-            ),
-            Theme::Halloween => ("halloween", include_str!("../asset/theme-halloween.js")),
-            Theme::Custom(name, content) => (name, content),
+            Theme::Default => "",
+            Theme::Dark => "dark",
+            Theme::Vintage => "vintage",
+            Theme::Westeros => "westeros",
+            Theme::Essos => "essos",
+            Theme::Wonderland => "wonderland",
+            Theme::Walden => "walden",
+            Theme::Chalk => "chalk",
+            Theme::Infographic => "infographic",
+            Theme::Macarons => "macarons",
+            Theme::Roma => "roma",
+            Theme::Shine => "shine",
+            Theme::PurplePassion => "purple-passion",
+            Theme::Halloween => "halloween",
+            Theme::Custom(name, _) => name,
+            Theme::Definition(definition) => definition.name(),
         }
     }
+
+    /// The name of the theme as a JavaScript string literal for a `<script>`
+    /// element, or `None` for the default theme.
+    #[cfg(feature = "html")]
+    pub(crate) fn name_literal(&self) -> Option<String> {
+        let name = self.name();
+        (!name.is_empty())
+            .then(|| crate::element::escape_script_data(&serde_json::Value::from(name).to_string()))
+    }
+
+    /// The script registering the theme with ECharts, empty for the themes
+    /// built into ECharts.
+    #[cfg(feature = "html")]
+    pub(crate) fn source(&self) -> Result<Cow<'static, str>, EchartsError> {
+        Ok(Cow::Borrowed(match self {
+            Theme::Default | Theme::Dark => "",
+            Theme::Vintage => include_str!("../asset/theme-vintage.js"),
+            Theme::Westeros => include_str!("../asset/theme-westeros.js"),
+            Theme::Essos => include_str!("../asset/theme-essos.js"),
+            Theme::Wonderland => include_str!("../asset/theme-wonderland.js"),
+            Theme::Walden => include_str!("../asset/theme-walden.js"),
+            Theme::Chalk => include_str!("../asset/theme-chalk.js"),
+            Theme::Infographic => include_str!("../asset/theme-infographic.js"),
+            Theme::Macarons => include_str!("../asset/theme-macarons.js"),
+            Theme::Roma => include_str!("../asset/theme-roma.js"),
+            Theme::Shine => include_str!("../asset/theme-shine.js"),
+            Theme::PurplePassion => include_str!("../asset/theme-purple-passion.js"),
+            Theme::Halloween => include_str!("../asset/theme-halloween.js"),
+            Theme::Custom(_, source) => source,
+            Theme::Definition(definition) => {
+                return definition.register_theme_script().map(Cow::Owned)
+            }
+        }))
+    }
 }
 
 impl TryFrom<&str> for Theme {
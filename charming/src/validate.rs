@@ -0,0 +1,443 @@
+//! Consistency checks of a [`Chart`], see [`Chart::validate`].
+
+use serde_json::{Map, Value};
+
+use crate::Chart;
+
+/// How bad a [`Diagnostic`] is.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Severity {
+    /// The chart renders, but likely not the way it was meant to, e.g. a
+    /// series with more data items than its category axis has categories.
+    Warning,
+    /// The chart, or part of it, fails to render, e.g. a series pointing at
+    /// an axis that does not exist.
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A problem found by [`Chart::validate`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    /// Path of the offending option, e.g. `series[0].xAxisIndex`
+    pub path: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at `{}`: {}", self.severity, self.path, self.message)
+    }
+}
+
+pub(crate) fn validate(chart: &Chart) -> Vec<Diagnostic> {
+    let option = serde_json::to_value(chart).unwrap_or_default();
+    let mut diagnostics = vec![];
+
+    let Some(base_option) = option.get("baseOption").and_then(Value::as_object) else {
+        if let Some(option) = option.as_object() {
+            Validator::new(option, "", &mut diagnostics).run(|_| true);
+        }
+        return diagnostics;
+    };
+
+    Validator::new(base_option, "baseOption.", &mut diagnostics).run(|_| true);
+
+    // Frames are merged into the base option by ECharts, check each merged
+    // frame but only report what the frame itself sets.
+    let frames = option.get("options").and_then(Value::as_array);
+    for (i, frame) in frames.into_iter().flatten().enumerate() {
+        let Some(frame) = frame.as_object() else {
+            continue;
+        };
+        let mut merged = base_option.clone();
+        for (key, value) in frame {
+            merged.insert(key.clone(), value.clone());
+        }
+        Validator::new(&merged, &format!("options[{i}]."), &mut diagnostics)
+            .run(|key| frame.contains_key(key));
+    }
+
+    diagnostics
+}
+
+/// Default coordinate system of the series types which have one.
+fn default_coordinate_system(series_type: &str) -> Option<&'static str> {
+    match series_type {
+        "bar" | "boxplot" | "candlestick" | "custom" | "effectScatter" | "heatmap" | "line"
+        | "pictorialBar" | "scatter" => Some("cartesian2d"),
+        "lines" => Some("geo"),
+        "bar3D" | "line3D" | "scatter3D" | "surface" => Some("cartesian3D"),
+        "parallel" => Some("parallel"),
+        "themeRiver" => Some("single"),
+        _ => None,
+    }
+}
+
+/// Coordinate systems a series type can be drawn on, `None` if any or if the
+/// series type is not known.
+fn coordinate_systems(series_type: &str) -> Option<&'static [&'static str]> {
+    Some(match series_type {
+        "bar" | "line" => &["cartesian2d", "polar"],
+        "boxplot" | "candlestick" | "pictorialBar" => &["cartesian2d"],
+        "effectScatter" | "scatter" => &["cartesian2d", "polar", "geo", "single", "calendar"],
+        "heatmap" => &["cartesian2d", "geo", "calendar"],
+        "lines" => &["cartesian2d", "geo", "polar"],
+        "graph" => &["cartesian2d", "polar", "geo", "calendar", "view", "none"],
+        "bar3D" | "scatter3D" => &["cartesian3D", "geo3D", "globe"],
+        "line3D" | "surface" => &["cartesian3D"],
+        "parallel" => &["parallel"],
+        "themeRiver" => &["single"],
+        _ => return None,
+    })
+}
+
+struct Validator<'a> {
+    option: &'a Map<String, Value>,
+    prefix: &'a str,
+    diagnostics: &'a mut Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(
+        option: &'a Map<String, Value>,
+        prefix: &'a str,
+        diagnostics: &'a mut Vec<Diagnostic>,
+    ) -> Self {
+        Self {
+            option,
+            prefix,
+            diagnostics,
+        }
+    }
+
+    /// Run the checks of the top level options for which `check` is true.
+    fn run(mut self, check: impl Fn(&str) -> bool) {
+        for axis in ["xAxis", "yAxis"] {
+            if check(axis) {
+                self.check_axes(axis, "gridIndex", "grid");
+            }
+        }
+        for axis in ["angleAxis", "radiusAxis"] {
+            if check(axis) {
+                self.check_axes(axis, "polarIndex", "polar");
+            }
+        }
+        if check("dataset") {
+            self.check_dataset();
+        }
+        if check("series") {
+            for (path, series) in self.components("series") {
+                self.check_series(&path, series);
+            }
+        }
+        if check("visualMap") {
+            for (path, visual_map) in self.components("visualMap") {
+                self.check_references(&path, visual_map, "seriesIndex", "series");
+            }
+        }
+        if check("dataZoom") {
+            for (path, data_zoom) in self.components("dataZoom") {
+                for (key, target) in [
+                    ("xAxisIndex", "xAxis"),
+                    ("yAxisIndex", "yAxis"),
+                    ("radiusAxisIndex", "radiusAxis"),
+                    ("angleAxisIndex", "angleAxis"),
+                    ("singleAxisIndex", "singleAxis"),
+                ] {
+                    self.check_references(&path, data_zoom, key, target);
+                }
+            }
+        }
+    }
+
+    fn check_axes(&mut self, axis: &str, key: &str, target: &str) {
+        for (path, component) in self.components(axis) {
+            self.check_references(&path, component, key, target);
+        }
+    }
+
+    fn check_dataset(&mut self) {
+        let ids = self.dataset_ids();
+        for (path, dataset) in self.components("dataset") {
+            self.check_references(&path, dataset, "fromDatasetIndex", "dataset");
+            if let Some(id) = dataset.get("fromDatasetId").and_then(Value::as_str) {
+                if !ids.iter().any(|i| i == id) {
+                    self.error(
+                        format!("{path}.fromDatasetId"),
+                        format!("no dataset has the id \"{id}\""),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_series(&mut self, path: &str, series: &Value) {
+        let series_type = series.get("type").and_then(Value::as_str).unwrap_or("");
+        let explicit = series.get("coordinateSystem").and_then(Value::as_str);
+
+        if let (Some(system), Some(allowed)) = (explicit, coordinate_systems(series_type)) {
+            if !allowed.contains(&system) {
+                self.error(
+                    format!("{path}.coordinateSystem"),
+                    format!(
+                        "a {series_type} series cannot be drawn on the {system} coordinate system"
+                    ),
+                );
+            }
+        }
+
+        match explicit.or_else(|| default_coordinate_system(series_type)) {
+            Some("cartesian2d") => {
+                self.check_required(path, series, "xAxisIndex", "xAxis");
+                self.check_required(path, series, "yAxisIndex", "yAxis");
+                self.check_category_lengths(path, series);
+            }
+            Some("polar") => self.check_required(path, series, "polarIndex", "polar"),
+            Some("geo") => self.check_required(path, series, "geoIndex", "geo"),
+            Some("calendar") => self.check_required(path, series, "calendarIndex", "calendar"),
+            Some("single") => self.check_required(path, series, "singleAxisIndex", "singleAxis"),
+            Some("parallel") => {
+                self.check_required(path, series, "parallelIndex", "parallel");
+                if self.count("parallelAxis") == 0 {
+                    self.error(
+                        path.to_string(),
+                        "a parallel series needs at least one parallelAxis".to_string(),
+                    );
+                }
+            }
+            Some("cartesian3D") => self.check_required(path, series, "grid3DIndex", "grid3D"),
+            _ => {}
+        }
+        if series_type == "radar" {
+            self.check_required(path, series, "radarIndex", "radar");
+        }
+        if series_type == "map" {
+            self.check_references(path, series, "geoIndex", "geo");
+        }
+
+        self.check_dataset_reference(path, series);
+        self.check_encode(path, series);
+    }
+
+    /// Check a reference to a component the series cannot do without, which
+    /// is the first one unless given.
+    fn check_required(&mut self, path: &str, series: &Value, key: &str, target: &str) {
+        if series.get(key).is_some() {
+            self.check_references(path, series, key, target);
+        } else if self.count(target) == 0 {
+            let series_type = series.get("type").and_then(Value::as_str).unwrap_or("");
+            self.error(
+                path.to_string(),
+                format!("a {series_type} series needs a {target} component, the chart has none"),
+            );
+        }
+    }
+
+    /// Check that the indices set in `key` of `component` are within the
+    /// `target` components.
+    fn check_references(&mut self, path: &str, component: &Value, key: &str, target: &str) {
+        let count = self.count(target);
+        let references = match component.get(key) {
+            Some(Value::Array(indices)) => indices
+                .iter()
+                .enumerate()
+                .map(|(i, index)| (format!("{path}.{key}[{i}]"), index))
+                .collect(),
+            Some(index) => vec![(format!("{path}.{key}"), index)],
+            None => vec![],
+        };
+        for (path, index) in references {
+            let Some(index) = index.as_f64() else {
+                continue;
+            };
+            if index < 0.0 || index >= count as f64 {
+                self.error(
+                    path,
+                    format!("{key} {index} refers to a missing {target}, the chart has {count}"),
+                );
+            }
+        }
+    }
+
+    fn check_dataset_reference(&mut self, path: &str, series: &Value) {
+        self.check_references(path, series, "datasetIndex", "dataset");
+        if let Some(id) = series.get("datasetId").and_then(Value::as_str) {
+            if !self.dataset_ids().iter().any(|i| i == id) {
+                self.error(
+                    format!("{path}.datasetId"),
+                    format!("no dataset has the id \"{id}\""),
+                );
+            }
+        }
+    }
+
+    /// Check that the dimension names of the series' `encode` exist.
+    fn check_encode(&mut self, path: &str, series: &Value) {
+        let Some(encode) = series.get("encode").and_then(Value::as_object) else {
+            return;
+        };
+        let Some(dimensions) = self.dimensions(series) else {
+            return;
+        };
+        for (key, value) in encode {
+            let names: Vec<(String, &str)> = match value {
+                Value::String(name) => vec![(format!("{path}.encode.{key}"), name)],
+                Value::Array(values) => values
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, v)| Some((format!("{path}.encode.{key}[{i}]"), v.as_str()?)))
+                    .collect(),
+                _ => vec![],
+            };
+            for (path, name) in names {
+                if !dimensions.iter().any(|d| d == name) {
+                    self.error(
+                        path,
+                        format!(
+                            "unknown dimension \"{name}\", the dimensions are {}",
+                            dimensions.join(", ")
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Warn about series whose data does not line up with the categories of
+    /// their category axes.
+    fn check_category_lengths(&mut self, path: &str, series: &Value) {
+        let Some(data) = series.get("data").and_then(Value::as_array) else {
+            return;
+        };
+        // Items given as `[x, y]` pairs are matched to categories by value.
+        let positional = data.iter().all(|item| match item {
+            Value::Array(_) => false,
+            Value::Object(item) => !matches!(item.get("value"), Some(Value::Array(_))),
+            _ => true,
+        });
+        if !positional {
+            return;
+        }
+
+        for (key, axis) in [("xAxisIndex", "xAxis"), ("yAxisIndex", "yAxis")] {
+            let index = series.get(key).and_then(Value::as_u64).unwrap_or(0) as usize;
+            let Some((axis_path, axis)) = self.components(axis).into_iter().nth(index) else {
+                continue;
+            };
+            let category = match axis.get("type").and_then(Value::as_str) {
+                Some(axis_type) => axis_type == "category",
+                None => axis.get("data").is_some(),
+            };
+            let categories = axis.get("data").and_then(Value::as_array);
+            if let (true, Some(categories)) = (category, categories) {
+                if categories.len() != data.len() {
+                    self.warning(
+                        format!("{path}.data"),
+                        format!(
+                            "the series has {} data items but {axis_path} has {} categories",
+                            data.len(),
+                            categories.len()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Dimension names of a series, from its own `dimensions` or those of
+    /// the dataset it uses, if known.
+    fn dimensions(&self, series: &Value) -> Option<Vec<String>> {
+        if let Some(dimensions) = dimension_names(series) {
+            return Some(dimensions);
+        }
+        let datasets = self.components("dataset");
+        let dataset = match series.get("datasetId").and_then(Value::as_str) {
+            Some(id) => datasets
+                .iter()
+                .find(|(_, d)| d.get("id").and_then(Value::as_str) == Some(id)),
+            None => {
+                let index = series.get("datasetIndex").and_then(Value::as_u64);
+                datasets.get(index.unwrap_or(0) as usize)
+            }
+        }?;
+        dimension_names(dataset.1).or_else(|| {
+            // Without dimensions, a header row of strings names them.
+            let header = dataset.1.get("source")?.as_array()?.first()?.as_array()?;
+            header
+                .iter()
+                .map(|name| name.as_str().map(str::to_string))
+                .collect()
+        })
+    }
+
+    fn dataset_ids(&self) -> Vec<String> {
+        self.components("dataset")
+            .into_iter()
+            .filter_map(|(_, d)| Some(d.get("id")?.as_str()?.to_string()))
+            .collect()
+    }
+
+    /// The components set in `key`, with their paths, whether the option
+    /// holds a single one or a list.
+    fn components(&self, key: &str) -> Vec<(String, &'a Value)> {
+        match self.option.get(key) {
+            Some(Value::Array(components)) => components
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (format!("{}{key}[{i}]", self.prefix), c))
+                .collect(),
+            Some(component @ Value::Object(_)) => {
+                vec![(format!("{}{key}", self.prefix), component)]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn count(&self, key: &str) -> usize {
+        let count = self.components(key).len();
+        // ECharts creates a grid for cartesian axes, and a parallel for
+        // parallel axes, when none is given.
+        if key == "grid" || (key == "parallel" && self.option.contains_key("parallelAxis")) {
+            count.max(1)
+        } else {
+            count
+        }
+    }
+
+    fn error(&mut self, path: String, message: String) {
+        self.push(path, Severity::Error, message);
+    }
+
+    fn warning(&mut self, path: String, message: String) {
+        self.push(path, Severity::Warning, message);
+    }
+
+    fn push(&mut self, path: String, severity: Severity, message: String) {
+        self.diagnostics.push(Diagnostic {
+            path,
+            severity,
+            message,
+        });
+    }
+}
+
+fn dimension_names(component: &Value) -> Option<Vec<String>> {
+    let dimensions = component.get("dimensions")?.as_array()?;
+    dimensions
+        .iter()
+        .map(|dimension| match dimension {
+            Value::String(name) => Some(name.clone()),
+            dimension => Some(dimension.get("name")?.as_str()?.to_string()),
+        })
+        .collect()
+}
@@ -0,0 +1,98 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    action::{Action, ActionTarget, BrushArea, DataZoomAction, ShowTipAction},
+    component::BrushType,
+};
+use serde_json::json;
+
+#[test]
+fn data_item_actions() {
+    let actions = vec![
+        Action::Highlight(ActionTarget::new().series_index(0).data_index(2)),
+        Action::Downplay(ActionTarget::new().series_name("Sales")),
+        Action::ToggleSelect(ActionTarget::new().series_index(vec![0, 1]).name("Wed")),
+        Action::MapSelect(ActionTarget::new().series_index(0).name("Ontario")),
+        Action::ShowTip(ShowTipAction::new().series_index(0).data_index(3)),
+        Action::HideTip,
+    ];
+
+    assert_json_eq!(
+        serde_json::to_value(&actions).unwrap(),
+        json!([
+            { "type": "highlight", "seriesIndex": 0, "dataIndex": 2 },
+            { "type": "downplay", "seriesName": "Sales" },
+            { "type": "toggleSelect", "seriesIndex": [0, 1], "name": "Wed" },
+            { "type": "mapSelect", "seriesIndex": 0, "name": "Ontario" },
+            { "type": "showTip", "seriesIndex": 0, "dataIndex": 3 },
+            { "type": "hideTip" }
+        ])
+    );
+
+    let deserialized: Vec<Action> =
+        serde_json::from_str(&serde_json::to_string(&actions).unwrap()).unwrap();
+    assert_eq!(deserialized, actions);
+}
+
+#[test]
+fn component_actions() {
+    let actions = vec![
+        Action::DataZoom(DataZoomAction::new().data_zoom_index(0).start(20).end(80)),
+        Action::DataZoom(DataZoomAction::new().data_zoom_index(vec![0, 1]).start(50)),
+        Action::DataZoom(
+            DataZoomAction::new()
+                .start_value("2024-01-01")
+                .end_value("2024-03-31"),
+        ),
+        Action::LegendUnSelect {
+            name: "Costs".to_string(),
+        },
+        Action::LegendAllSelect,
+        Action::Brush {
+            areas: vec![BrushArea::new()
+                .brush_type(BrushType::Rect)
+                .coord_range(vec![vec![0, 10], vec![5, 50]])
+                .x_axis_index(0)
+                .y_axis_index(0)],
+        },
+        Action::Brush {
+            areas: vec![BrushArea::new()
+                .brush_type(BrushType::Polygon)
+                .coord_range(vec![vec![100, 30], vec![110, 40], vec![105, 45]])
+                .geo_index(0)],
+        },
+        Action::TimelineChange { current_index: 2 },
+    ];
+
+    assert_json_eq!(
+        serde_json::to_value(&actions).unwrap(),
+        json!([
+            { "type": "dataZoom", "dataZoomIndex": 0, "start": 20.0, "end": 80.0 },
+            { "type": "dataZoom", "dataZoomIndex": [0, 1], "start": 50.0 },
+            { "type": "dataZoom", "startValue": "2024-01-01", "endValue": "2024-03-31" },
+            { "type": "legendUnSelect", "name": "Costs" },
+            { "type": "legendAllSelect" },
+            {
+                "type": "brush",
+                "areas": [{
+                    "brushType": "rect",
+                    "coordRange": [[0, 10], [5, 50]],
+                    "xAxisIndex": 0,
+                    "yAxisIndex": 0
+                }]
+            },
+            {
+                "type": "brush",
+                "areas": [{
+                    "brushType": "polygon",
+                    "coordRange": [[100, 30], [110, 40], [105, 45]],
+                    "geoIndex": 0
+                }]
+            },
+            { "type": "timelineChange", "currentIndex": 2 }
+        ])
+    );
+
+    let deserialized: Vec<Action> =
+        serde_json::from_str(&serde_json::to_string(&actions).unwrap()).unwrap();
+    assert_eq!(deserialized, actions);
+}
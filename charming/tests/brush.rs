@@ -0,0 +1,61 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    component::{Brush, BrushMode, BrushThrottleType, BrushType, BrushVisual},
+    element::Symbol,
+    Chart,
+};
+use serde_json::json;
+
+#[test]
+fn brush_component() {
+    let echarts_json = json!(
+      {
+        "brush": {
+          "toolbox": ["rect", "polygon", "lineX", "lineY", "keep", "clear"],
+          "brushLink": "all",
+          "xAxisIndex": [0, 1],
+          "seriesIndex": "all",
+          "brushType": "lineX",
+          "brushMode": "multiple",
+          "transformable": true,
+          "throttleType": "fixRate",
+          "throttleDelay": 100.0,
+          "inBrush": {
+            "symbol": "diamond",
+            "color": "#e54035"
+          },
+          "outOfBrush": {
+            "colorAlpha": 0.1
+          }
+        }
+      }
+    );
+
+    let chart = Chart::new().brush(
+        Brush::new()
+            .toolbox(vec![
+                BrushType::Rect,
+                BrushType::Polygon,
+                BrushType::LineX,
+                BrushType::LineY,
+                BrushType::Keep,
+                BrushType::Clear,
+            ])
+            .brush_link("all")
+            .x_axis_index(vec![0, 1])
+            .series_index("all")
+            .brush_type(BrushType::LineX)
+            .brush_mode(BrushMode::Multiple)
+            .transformable(true)
+            .throttle_type(BrushThrottleType::FixRate)
+            .throttle_delay(100.0)
+            .in_brush(BrushVisual::new().symbol(Symbol::Diamond).color("#e54035"))
+            .out_of_brush(BrushVisual::new().color_alpha(0.1)),
+    );
+
+    let charming_json = serde_json::from_str::<serde_json::Value>(&chart.to_string()).unwrap();
+    assert_json_eq!(echarts_json, charming_json);
+
+    let deserialized: Chart = serde_json::from_value(charming_json).unwrap();
+    assert_eq!(chart, deserialized);
+}
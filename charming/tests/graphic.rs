@@ -0,0 +1,108 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    component::{Graphic, GraphicBounding, GraphicShape, GraphicStyle, GraphicType},
+    element::JsFunction,
+    Chart,
+};
+use serde_json::json;
+
+#[test]
+fn graphic_watermark() {
+    let echarts_json = json!(
+      {
+        "graphic": [
+          {
+            "type": "group",
+            "rotation": 0.785,
+            "bounding": "raw",
+            "right": 110,
+            "bottom": 110,
+            "z": 100.0,
+            "children": [
+              {
+                "type": "rect",
+                "left": "center",
+                "top": "center",
+                "z": 100.0,
+                "shape": {
+                  "width": 400.0,
+                  "height": 50.0
+                },
+                "style": {
+                  "fill": "rgba(0,0,0,0.3)"
+                }
+              },
+              {
+                "type": "text",
+                "left": "center",
+                "top": "center",
+                "z": 100.0,
+                "style": {
+                  "fill": "#fff",
+                  "text": "ECHARTS LINE CHART",
+                  "font": "bold 26px sans-serif"
+                }
+              }
+            ]
+          },
+          {
+            "type": "polyline",
+            "shape": {
+              "points": [[0.0, 0.0], [10.0, 5.0], [20.0, 0.0]]
+            },
+            "onclick": "function() { alert('clicked') }"
+          }
+        ]
+      }
+    );
+
+    let chart = Chart::new()
+        .graphic(
+            Graphic::new()
+                .type_(GraphicType::Group)
+                .rotation(0.785)
+                .bounding(GraphicBounding::Raw)
+                .right(110)
+                .bottom(110)
+                .z(100.0)
+                .children(vec![
+                    Graphic::new()
+                        .type_(GraphicType::Rect)
+                        .left("center")
+                        .top("center")
+                        .z(100.0)
+                        .shape(GraphicShape::new().width(400.0).height(50.0))
+                        .style(GraphicStyle::new().fill("rgba(0,0,0,0.3)")),
+                    Graphic::new()
+                        .type_(GraphicType::Text)
+                        .left("center")
+                        .top("center")
+                        .z(100.0)
+                        .style(
+                            GraphicStyle::new()
+                                .fill("#fff")
+                                .text("ECHARTS LINE CHART")
+                                .font("bold 26px sans-serif"),
+                        ),
+                ]),
+        )
+        .graphic(
+            Graphic::new()
+                .type_(GraphicType::Polyline)
+                .shape(GraphicShape::new().points(vec![(0.0, 0.0), (10.0, 5.0), (20.0, 0.0)]))
+                .onclick(JsFunction::new_with_args("", "alert('clicked')")),
+        );
+
+    let charming_json = serde_json::to_value(&chart).unwrap();
+    let onclick = charming_json["graphic"][1]["onclick"].as_str().unwrap();
+    assert!(chart
+        .to_string()
+        .contains("\"onclick\": function() { alert('clicked') }"));
+
+    let mut expected = echarts_json;
+    expected["graphic"][1]["onclick"] = onclick.into();
+    assert_json_eq!(expected, charming_json);
+
+    let deserialized: Chart = serde_json::from_value(charming_json).unwrap();
+    assert_eq!(chart, deserialized);
+}
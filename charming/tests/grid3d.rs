@@ -0,0 +1,84 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    component::{Axis3D, Grid3D, Projection, ViewControl},
+    element::{AxisType, CoordinateSystem, JsFunction},
+    series::{Scatter3d, Surface, SurfaceEquation, SurfaceRange},
+    Chart,
+};
+use serde_json::json;
+
+#[test]
+fn scatter3d_in_grid3d() {
+    let echarts_json = json!(
+      {
+        "grid3D": [
+          {
+            "boxWidth": 100.0,
+            "viewControl": {
+              "projection": "orthographic",
+              "autoRotate": true,
+              "center": [0.0, 10.0, 0.0]
+            }
+          }
+        ],
+        "xAxis3D": [{ "type": "category", "data": ["a", "b"] }],
+        "yAxis3D": [{ "type": "value", "grid3DIndex": 0.0 }],
+        "zAxis3D": [{ "type": "value" }],
+        "series": [
+          {
+            "type": "scatter3D",
+            "coordinateSystem": "cartesian3D",
+            "grid3DIndex": 0,
+            "symbolSize": 12.0,
+            "data": [[0, 1, 2], [1, 3, 4]]
+          }
+        ]
+      }
+    );
+
+    let chart = Chart::new()
+        .grid3d(
+            Grid3D::new().box_width(100).view_control(
+                ViewControl::new()
+                    .projection(Projection::Orthographic)
+                    .auto_rotate(true)
+                    .center((0, 10, 0)),
+            ),
+        )
+        .x_axis3d(
+            Axis3D::new()
+                .type_(AxisType::Category)
+                .data(vec!["a".to_string(), "b".to_string()]),
+        )
+        .y_axis3d(Axis3D::new().type_(AxisType::Value).grid3d_index(0))
+        .z_axis3d(Axis3D::new().type_(AxisType::Value))
+        .series(
+            Scatter3d::new()
+                .coordinate_system(CoordinateSystem::Cartesian3d)
+                .grid3d_index(0)
+                .symbol_size(12)
+                .data(vec![vec![0, 1, 2], vec![1, 3, 4]]),
+        );
+
+    let charming_json = serde_json::from_str::<serde_json::Value>(&chart.to_string()).unwrap();
+    assert_json_eq!(echarts_json, charming_json);
+
+    let deserialized: Chart = serde_json::from_value(charming_json).unwrap();
+    assert_eq!(chart, deserialized);
+}
+
+#[test]
+fn surface_equation() {
+    let chart = Chart::new().grid3d(Grid3D::new()).series(
+        Surface::new().equation(
+            SurfaceEquation::new()
+                .x(SurfaceRange::new().step(0.05).min(-1).max(1))
+                .y(SurfaceRange::new().step(0.05).min(-1).max(1))
+                .z(JsFunction::new_with_args("x, y", "return Math.sin(x * y);")),
+        ),
+    );
+
+    let json = chart.to_string();
+    assert!(json.contains(r#""type": "surface""#));
+    assert!(json.contains("\"z\": function(x, y) { return Math.sin(x * y); }"));
+}
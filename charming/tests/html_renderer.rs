@@ -0,0 +1,460 @@
+use charming::{
+    component::{Axis, Feature, GeoMap, GeoMapOpt, SaveAsImage, SaveAsImageType, Title, Toolbox},
+    element::{JsFunction, Tooltip},
+    series::Bar,
+    theme::{Theme, ThemeDefinition},
+    Chart, Dashboard, FontFace, FontFormat, HtmlRenderer, Panel, Size, ECHARTS_SCRIPT_NAME,
+};
+use serde_json::json;
+
+#[test]
+fn geo_maps_are_registered_before_set_option() {
+    let chart = Chart::new().geo_map(GeoMap::new().name("regions").opt(GeoMapOpt::GeoJson {
+        value: json!({ "type": "FeatureCollection", "features": [] }),
+        special_areas: serde_json::Value::Null,
+    }));
+
+    let html = HtmlRenderer::new("geo", 800, 600).render(&chart).unwrap();
+
+    let register = html
+        .find(r#"echarts.registerMap("regions", {"geoJSON":{"#)
+        .expect("geo map should be registered");
+    let set_option = html.find("chart.setOption(option)").unwrap();
+    assert!(register < set_option);
+}
+
+#[test]
+fn offline_inlines_echarts_and_theme() {
+    let html = HtmlRenderer::new("offline", 800, 600)
+        .offline(true)
+        .theme(Theme::Vintage)
+        .render(&Chart::new())
+        .unwrap();
+
+    assert!(!html.contains("cdn.jsdelivr.net"));
+    assert!(html.contains("Apache Software Foundation"));
+    assert!(html.contains("echarts.registerTheme('vintage'"));
+    assert!(html.contains(r#""vintage", {"#));
+}
+
+#[test]
+fn online_loads_echarts_from_cdn() {
+    let html = HtmlRenderer::new("online", 800, 600)
+        .render(&Chart::new())
+        .unwrap();
+
+    assert!(html.contains("https://cdn.jsdelivr.net/npm/echarts@5.5.1/dist/echarts.min.js"));
+    assert!(html.contains("renderer: 'canvas'"));
+}
+
+#[test]
+fn svg_save_as_image_uses_svg_renderer() {
+    let chart = Chart::new().toolbox(
+        Toolbox::new()
+            .feature(Feature::new().save_as_image(SaveAsImage::new().type_(SaveAsImageType::Svg))),
+    );
+
+    let html = HtmlRenderer::new("svg", 800, 600).render(&chart).unwrap();
+
+    assert!(html.contains("renderer: 'svg'"));
+}
+
+#[test]
+fn font_faces_are_declared() {
+    let html = HtmlRenderer::new("fonts", 800, 600)
+        .font_face(
+            FontFace::new("Corporate Sans", "https://example.com/corporate.woff2")
+                .format(FontFormat::Woff2)
+                .weight("bold"),
+        )
+        .font_face(FontFace::from_data("Embedded", b"Ma", FontFormat::TrueType))
+        .render(&Chart::new())
+        .unwrap();
+
+    assert!(html.contains(
+        r#"@font-face { font-family: "Corporate Sans"; src: url("https://example.com/corporate.woff2") format("woff2"); font-weight: bold; }"#
+    ));
+    assert!(html.contains(
+        r#"@font-face { font-family: "Embedded"; src: url("data:font/ttf;base64,TWE=") format("truetype"); }"#
+    ));
+}
+
+#[test]
+fn font_face_family_cannot_close_style() {
+    let html = HtmlRenderer::new("fonts", 800, 600)
+        .font_face(FontFace::new(
+            r#"x"; }</style><script>alert(1)</script>"#,
+            "a.woff",
+        ))
+        .render(&Chart::new())
+        .unwrap();
+
+    assert!(!html.contains("</style><script>"));
+    assert!(html.contains(r#"font-family: "x\22 ; }\3c /style\3e "#));
+}
+
+#[test]
+fn dashboard_lays_out_panels() {
+    let dashboard = Dashboard::new(3)
+        .row_height(250)
+        .gap(8)
+        .panel(Panel::new(Chart::new()).column_span(2))
+        .panel(
+            Panel::new(Chart::new())
+                .id("errors")
+                .column(3)
+                .row(1)
+                .row_span(2),
+        )
+        .panel(Chart::new());
+
+    let html = HtmlRenderer::new("dashboard", 1200, 400)
+        .render_dashboard(&dashboard)
+        .unwrap();
+
+    assert!(html.contains(
+        "grid-template-columns: repeat(3, minmax(0, 1fr)); grid-auto-rows: 250px; gap: 8px"
+    ));
+    assert!(html.contains(r#"<div class="panel" id="chart-0" style="grid-column: span 2"></div>"#));
+    assert!(html.contains(
+        r#"<div class="panel" id="errors" style="grid-column: 3 / span 1; grid-row: 1 / span 2"></div>"#
+    ));
+    assert!(html.contains(r#"<div class="panel" id="chart-2"></div>"#));
+    assert_eq!(html.matches("echarts.init(").count(), 3);
+    assert!(html.contains("window.addEventListener('resize'"));
+}
+
+#[test]
+fn dashboard_connects_groups() {
+    let dashboard = Dashboard::new(2)
+        .panel(Panel::new(Chart::new()).group("traffic"))
+        .panel(Panel::new(Chart::new()).group("traffic"))
+        .panel(Panel::new(Chart::new()).group("</script>"));
+
+    let html = HtmlRenderer::new("dashboard", 1200, 400)
+        .render_dashboard(&dashboard)
+        .unwrap();
+
+    assert_eq!(html.matches(r#"chart.group = "traffic";"#).count(), 2);
+    assert_eq!(html.matches(r#"echarts.connect("traffic");"#).count(), 1);
+    assert!(html.contains(r#"echarts.connect("<\/script>");"#));
+    assert_eq!(html.matches("</script>").count(), 3);
+}
+
+#[test]
+fn dashboard_rejects_bad_ids() {
+    let renderer = HtmlRenderer::new("dashboard", 1200, 400);
+
+    let duplicate = Dashboard::new(2)
+        .panel(Panel::new(Chart::new()).id("chart-1"))
+        .panel(Chart::new());
+    assert!(renderer.render_dashboard(&duplicate).is_err());
+
+    let invalid = Dashboard::new(2).panel(Panel::new(Chart::new()).id("a' + b"));
+    assert!(renderer.render_dashboard(&invalid).is_err());
+}
+
+#[test]
+fn fragments_have_unique_ids() {
+    let renderer = HtmlRenderer::new("fragment", 800, 600);
+
+    let first = renderer.render_fragment(&Chart::new()).unwrap();
+    let second = renderer.render_fragment(&Chart::new()).unwrap();
+
+    let id = |html: &str| {
+        let start = html.find(r#"<div id=""#).unwrap() + 9;
+        html[start..start + html[start..].find('"').unwrap()].to_string()
+    };
+    assert!(id(&first).starts_with("charming-"));
+    assert_ne!(id(&first), id(&second));
+    assert!(first.contains(&format!("document.getElementById('{}')", id(&first))));
+    assert!(!first.contains("<html>"));
+    assert!(first.contains("echarts.min.js"));
+}
+
+#[test]
+fn fragment_with_id_without_echarts() {
+    let renderer = HtmlRenderer::new("fragment", 800, 600).include_echarts(false);
+
+    let html = renderer
+        .render_fragment_with_id("sales", &Chart::new())
+        .unwrap();
+
+    assert!(html.contains(r#"<div id="sales" style="width: 800px; height: 600px"></div>"#));
+    assert!(!html.contains("echarts.min.js"));
+    assert!(!html.contains("addEventListener"));
+    assert!(renderer
+        .render_fragment_with_id("sales'); alert(1); ('", &Chart::new())
+        .is_err());
+}
+
+#[test]
+fn responsive_fragment_resizes_with_window() {
+    let html = HtmlRenderer::new("fragment", 800, 600)
+        .width(Size::Percent(100.0))
+        .height(Size::Pixels(300))
+        .render_fragment_with_id("sales", &Chart::new())
+        .unwrap();
+
+    assert!(html.contains(r#"style="width: 100%; height: 300px""#));
+    assert!(html.contains("window.addEventListener('resize'"));
+}
+
+#[test]
+fn script_tags_follow_offline_mode() {
+    let online = HtmlRenderer::new("scripts", 800, 600).script_tags();
+    assert!(online.contains("echarts@5.5.1/dist/echarts.min.js"));
+
+    let offline = HtmlRenderer::new("scripts", 800, 600)
+        .offline(true)
+        .script_tags();
+    assert!(offline.starts_with("<script type=\"text/javascript\">"));
+    assert!(!offline.contains("cdn.jsdelivr.net"));
+}
+
+const HOSTILE: &str = "</script><script>alert(1)</script><!--\u{2028}\u{2029}";
+
+fn embedded_option(html: &str) -> serde_json::Value {
+    let start = html.find("var option = ").unwrap() + "var option = ".len();
+    let end = start + html[start..].find(";\n").unwrap();
+    serde_json::from_str(&html[start..end]).unwrap()
+}
+
+#[test]
+fn hostile_strings_cannot_close_the_script() {
+    let chart = Chart::new()
+        .title(Title::new().text(HOSTILE))
+        .tooltip(Tooltip::new().formatter(HOSTILE))
+        .x_axis(Axis::new().data(vec![HOSTILE, "b"]))
+        .y_axis(Axis::new())
+        .series(Bar::new().name(HOSTILE).data(vec![1, 2]));
+
+    let html = HtmlRenderer::new("hostile", 800, 600)
+        .render(&chart)
+        .unwrap();
+
+    // Two CDN scripts and the chart script
+    assert_eq!(html.matches("</script>").count(), 3);
+    assert!(!html.contains("<!--"));
+    assert!(!html.contains('\u{2028}') && !html.contains('\u{2029}'));
+
+    let option = embedded_option(&html);
+    assert_eq!(option["title"][0]["text"], HOSTILE);
+    assert_eq!(option["tooltip"]["formatter"], HOSTILE);
+    assert_eq!(option["xAxis"]["data"][0], HOSTILE);
+    assert_eq!(option["series"][0]["name"], HOSTILE);
+}
+
+#[test]
+fn hostile_strings_in_fragments_dashboards_and_maps() {
+    let chart =
+        Chart::new()
+            .title(Title::new().text(HOSTILE))
+            .geo_map(GeoMap::new().name(HOSTILE).opt(GeoMapOpt::GeoJson {
+                value: json!({ "type": "FeatureCollection", "features": [], "name": HOSTILE }),
+                special_areas: serde_json::Value::Null,
+            }));
+    let renderer = HtmlRenderer::new("hostile", 800, 600)
+        .include_echarts(false)
+        .theme(
+            ThemeDefinition::new(HOSTILE)
+                .background_color(HOSTILE)
+                .into(),
+        );
+
+    let fragment = renderer.render_fragment(&chart).unwrap();
+    assert_eq!(fragment.matches("</script>").count(), 1);
+    assert!(!fragment.contains("<!--"));
+
+    let dashboard = renderer
+        .render_dashboard(&Dashboard::new(1).panel(Panel::new(chart).group(HOSTILE)))
+        .unwrap();
+    assert_eq!(dashboard.matches("</script>").count(), 3);
+    assert!(!dashboard.contains("<!--"));
+}
+
+#[test]
+fn js_function_bodies_are_kept() {
+    let chart = Chart::new().tooltip(Tooltip::new().formatter(JsFunction::new_with_args(
+        "params",
+        "return '<b>' + params.name + '</b>';",
+    )));
+
+    let html = HtmlRenderer::new("function", 800, 600)
+        .render(&chart)
+        .unwrap();
+
+    assert!(html.contains("function(params) { return '<b>' + params.name + '</b>'; }"));
+}
+
+#[test]
+fn nonce_is_set_on_every_script_and_style() {
+    let chart = Chart::new().tooltip(
+        Tooltip::new().formatter(JsFunction::new_with_args("params", "return params.name;")),
+    );
+    let renderer = HtmlRenderer::new("csp", 800, 600)
+        .nonce("r4nd0m")
+        .font_face(FontFace::new("Inter", "/fonts/inter.woff2"));
+
+    let page = renderer.render(&chart).unwrap();
+    let fragment = renderer.render_fragment(&chart).unwrap();
+    let dashboard = renderer
+        .render_dashboard(&Dashboard::new(1).panel(chart))
+        .unwrap();
+
+    for html in [page, fragment, dashboard] {
+        assert_eq!(
+            html.matches("<script").count(),
+            html.matches(r#"nonce="r4nd0m""#).count() - html.matches("<style").count()
+        );
+        assert!(!html.contains("<script>") && !html.contains("<style>"));
+    }
+}
+
+#[test]
+fn nonce_is_escaped() {
+    let html = HtmlRenderer::new("csp", 800, 600)
+        .nonce(r#""><script>alert(1)</script>"#)
+        .render(&Chart::new())
+        .unwrap();
+
+    assert!(!html.contains("<script>alert(1)"));
+}
+
+#[test]
+fn external_page_has_no_inline_scripts() {
+    let chart = Chart::new().tooltip(
+        Tooltip::new().formatter(JsFunction::new_with_args("params", "return params.name;")),
+    );
+
+    let page = HtmlRenderer::new("csp", 800, 600)
+        .render_external(&chart, "assets/chart.js")
+        .unwrap();
+
+    assert_eq!(
+        page.html.matches("<script").count(),
+        page.html.matches("<script src=").count()
+    );
+    assert!(page
+        .html
+        .contains(r#"<script src="echarts.min.js"></script>"#));
+    assert!(page
+        .html
+        .contains(r#"<script src="assets/chart.js"></script>"#));
+    assert!(!page.html.contains("cdn.jsdelivr.net"));
+    assert!(!page.html.contains("setOption"));
+    assert!(page
+        .script
+        .contains("function(params) { return params.name; }"));
+    assert!(page.script.contains("chart.setOption(option);"));
+    assert!(page.echarts.contains("Apache Software Foundation"));
+}
+
+#[test]
+fn save_external_writes_page_script_and_echarts() {
+    let dir = std::env::temp_dir().join(format!("charming-external-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    HtmlRenderer::new("csp", 800, 600)
+        .save_external(&Chart::new(), dir.join("sales.html"))
+        .unwrap();
+
+    let html = std::fs::read_to_string(dir.join("sales.html")).unwrap();
+    assert!(html.contains(r#"<script src="sales.js"></script>"#));
+    assert!(std::fs::read_to_string(dir.join("sales.js"))
+        .unwrap()
+        .contains("echarts.init("));
+    assert!(dir.join(ECHARTS_SCRIPT_NAME).exists());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn event_handlers_are_registered_after_set_option() {
+    let renderer = HtmlRenderer::new("events", 800, 600)
+        .on(
+            "click",
+            JsFunction::new_with_args("params", "window.location.href = '/sales/' + params.name;"),
+        )
+        .on_query(
+            "legendselectchanged",
+            "series.bar",
+            JsFunction::new_with_args("params", "console.log(params.selected);"),
+        )
+        .on_query(
+            "datazoom",
+            json!({ "dataZoomIndex": 0 }),
+            JsFunction::new_with_args("params", "console.log(params.start);"),
+        );
+
+    let html = renderer.render(&Chart::new()).unwrap();
+
+    let set_option = html.find("chart.setOption(option);").unwrap();
+    let click = html
+        .find(r#"chart.on("click", function(params) { window.location.href = '/sales/' + params.name; });"#)
+        .unwrap();
+    assert!(set_option < click);
+    assert!(html.contains(
+        r#"chart.on("legendselectchanged", "series.bar", function(params) { console.log(params.selected); });"#
+    ));
+    assert!(html.contains(
+        r#"chart.on("datazoom", {"dataZoomIndex":0}, function(params) { console.log(params.start); });"#
+    ));
+
+    let fragment = renderer.render_fragment(&Chart::new()).unwrap();
+    assert_eq!(fragment.matches("chart.on(").count(), 3);
+    let dashboard = renderer
+        .render_dashboard(&Dashboard::new(2).panel(Chart::new()).panel(Chart::new()))
+        .unwrap();
+    assert_eq!(dashboard.matches("chart.on(").count(), 6);
+    let external = renderer.render_external(&Chart::new(), "chart.js").unwrap();
+    assert_eq!(external.script.matches("chart.on(").count(), 3);
+}
+
+#[test]
+fn panel_event_handlers_are_scoped_to_their_panel() {
+    let dashboard = Dashboard::new(2)
+        .panel(Panel::new(Chart::new()).id("sales").on(
+            "click",
+            JsFunction::new_with_args("params", "sales(params);"),
+        ))
+        .panel(Panel::new(Chart::new()).id("costs").on_query(
+            "click",
+            "series.bar",
+            JsFunction::new_with_args("params", "costs(params);"),
+        ));
+
+    let html = HtmlRenderer::new("events", 1200, 400)
+        .on(
+            "datazoom",
+            JsFunction::new_with_args("params", "zoom(params);"),
+        )
+        .render_dashboard(&dashboard)
+        .unwrap();
+
+    assert_eq!(html.matches(r#"chart.on("datazoom""#).count(), 2);
+    let sales = html.find("getElementById('sales')").unwrap();
+    let costs = html.find("getElementById('costs')").unwrap();
+    let sales_click = html
+        .find(r#"chart.on("click", function(params) { sales(params); });"#)
+        .unwrap();
+    let costs_click = html
+        .find(r#"chart.on("click", "series.bar", function(params) { costs(params); });"#)
+        .unwrap();
+    assert!(sales < sales_click && sales_click < costs);
+    assert!(costs < costs_click);
+    assert_eq!(html.matches("chart.on(").count(), 4);
+}
+
+#[test]
+fn event_names_and_queries_are_escaped() {
+    let html = HtmlRenderer::new("events", 800, 600)
+        .on_query(
+            HOSTILE,
+            HOSTILE,
+            JsFunction::new_with_args("params", "return;"),
+        )
+        .render(&Chart::new())
+        .unwrap();
+
+    assert_eq!(html.matches("</script>").count(), 3);
+}
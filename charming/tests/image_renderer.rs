@@ -0,0 +1,195 @@
+#![cfg(feature = "ssr")]
+
+use charming::{
+    component::{Axis, Timeline},
+    element::{JsFunction, Label},
+    series::Bar,
+    Chart, ConsoleLevel, ConsoleMessage, EchartsError, ImageRenderer,
+};
+
+fn timeline() -> Timeline {
+    Timeline::new().data(vec!["2002", "2003"])
+}
+
+fn timeline_chart() -> Chart {
+    timeline_chart_with(timeline())
+}
+
+fn timeline_chart_with(timeline: Timeline) -> Chart {
+    Chart::new()
+        .base_option(
+            Chart::new()
+                .timeline(timeline)
+                .x_axis(Axis::new().data(vec!["Beijing", "Tianjin"]))
+                .y_axis(Axis::new()),
+        )
+        .options(vec![
+            Chart::new().series(Bar::new().name("2002").data(vec![4315, 2150])),
+            Chart::new().series(Bar::new().name("2003").data(vec![5007, 2578])),
+        ])
+}
+
+#[test]
+fn render_frame() {
+    let mut renderer = ImageRenderer::new(400, 300);
+    let first = renderer.render_frame(&timeline_chart(), 0).unwrap();
+    let second = renderer.render_frame(&timeline_chart(), 1).unwrap();
+    assert!(first.starts_with("<svg"));
+    assert_ne!(first, second);
+    assert_eq!(
+        second,
+        renderer
+            .render(&timeline_chart_with(timeline().current_index(1)))
+            .unwrap()
+    );
+}
+
+#[test]
+fn render_frame_errors() {
+    let mut renderer = ImageRenderer::new(400, 300);
+    assert_eq!(
+        renderer
+            .render_frame(&timeline_chart(), 2)
+            .unwrap_err()
+            .to_string(),
+        "Image rendering error: Timeline frame 2 is out of range, the chart has 2 frames"
+    );
+
+    let chart = Chart::new()
+        .x_axis(Axis::new().data(vec!["Beijing", "Tianjin"]))
+        .y_axis(Axis::new())
+        .series(Bar::new().data(vec![4315, 2150]));
+    assert_eq!(
+        renderer.render_frame(&chart, 0).unwrap_err().to_string(),
+        "Image rendering error: The chart has no timeline"
+    );
+}
+
+#[cfg(feature = "ssr-raster")]
+#[test]
+fn render_format_frame_errors() {
+    use charming::ImageFormat;
+
+    let mut renderer = ImageRenderer::new(400, 300);
+    assert!(matches!(
+        renderer.render_format_frame(ImageFormat::Png, &timeline_chart(), 5),
+        Err(EchartsError::ImageRenderingError(_))
+    ));
+}
+
+#[test]
+fn svg_maps_are_an_error() {
+    use charming::component::GeoMap;
+
+    let chart = Chart::new().options(vec![
+        Chart::new().geo_map(GeoMap::from(("districts", "<svg></svg>")))
+    ]);
+    match ImageRenderer::new(400, 300).render(&chart) {
+        Err(EchartsError::ImageRenderingError(message)) => assert!(message.contains("districts")),
+        result => panic!("expected an error for the SVG map, got {result:?}"),
+    }
+}
+
+#[cfg(feature = "ssr-pdf")]
+#[test]
+fn render_pdf_pages() {
+    let chart = |data: Vec<i32>| {
+        Chart::new()
+            .x_axis(Axis::new().data(vec!["Beijing", "Tianjin"]))
+            .y_axis(Axis::new())
+            .series(Bar::new().data(data))
+    };
+    let mut renderer = ImageRenderer::new(400, 300);
+
+    let pdf = renderer.render_pdf(&chart(vec![4315, 2150])).unwrap();
+    assert_eq!(
+        lopdf::Document::load_mem(&pdf).unwrap().get_pages().len(),
+        1
+    );
+
+    let pdf = renderer
+        .render_pdf_pages(&[chart(vec![4315, 2150]), chart(vec![5007, 2578])])
+        .unwrap();
+    assert_eq!(
+        lopdf::Document::load_mem(&pdf).unwrap().get_pages().len(),
+        2
+    );
+
+    let path = std::env::temp_dir().join("charming-render-pdf-pages.pdf");
+    renderer.save_pdf(&chart(vec![1, 2]), &path).unwrap();
+    let document = lopdf::Document::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(document.get_pages().len(), 1);
+}
+
+#[test]
+fn heap_limit_too_small_for_echarts() {
+    let limit = 2 * 1024 * 1024;
+    let mut renderer = ImageRenderer::new(400, 300).heap_limit(limit);
+    for _ in 0..2 {
+        assert!(matches!(
+            renderer.render(&Chart::new()),
+            Err(EchartsError::HeapLimitExceeded { limit: bytes, .. }) if bytes == limit
+        ));
+    }
+}
+
+fn formatter_chart(body: &str) -> Chart {
+    Chart::new()
+        .x_axis(Axis::new().data(vec!["Beijing", "Tianjin"]))
+        .y_axis(Axis::new())
+        .series(
+            Bar::new()
+                .label(
+                    Label::new()
+                        .show(true)
+                        .formatter(JsFunction::new_with_args("params", body)),
+                )
+                .data(vec![4315, 2150]),
+        )
+}
+
+#[test]
+fn render_report_captures_console() {
+    let chart = formatter_chart(
+        "console.log('label', params.dataIndex); console.warn('slow'); return params.value;",
+    );
+    let report = ImageRenderer::new(400, 300).render_report(&chart).unwrap();
+
+    assert!(report.svg.starts_with("<svg"));
+    assert!(report.messages.contains(&ConsoleMessage {
+        level: ConsoleLevel::Log,
+        message: "label 1".to_string(),
+    }));
+    assert!(report
+        .warnings()
+        .all(|message| message.level == ConsoleLevel::Warn && message.message == "slow"));
+    assert!(report.warnings().count() > 0);
+}
+
+#[test]
+fn failed_render_keeps_console() {
+    let chart = formatter_chart("console.error('about to throw'); throw new Error('boom');");
+    match ImageRenderer::new(400, 300).render(&chart) {
+        Err(EchartsError::JsException(exception)) => {
+            assert_eq!(
+                exception.option_path.as_deref(),
+                Some("series[0].label.formatter")
+            );
+            assert!(exception.console.contains(&ConsoleMessage {
+                level: ConsoleLevel::Error,
+                message: "about to throw".to_string(),
+            }));
+        }
+        result => panic!("expected a JavaScript exception, got {result:?}"),
+    }
+
+    let chart = formatter_chart("console.log('looping'); while (true) {}");
+    let mut renderer = ImageRenderer::new(400, 300).timeout(std::time::Duration::from_millis(200));
+    match renderer.render(&chart) {
+        Err(EchartsError::Timeout { console, .. }) => {
+            assert_eq!(console[0].message, "looping");
+        }
+        result => panic!("expected a timeout, got {result:?}"),
+    }
+}
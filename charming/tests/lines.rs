@@ -0,0 +1,73 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    component::Geo,
+    element::{CoordinateSystem, LineStyle, Symbol},
+    series::{Lines, LinesData, LinesEffect},
+    Chart,
+};
+use serde_json::json;
+
+#[test]
+fn flight_routes() {
+    let echarts_json = json!(
+      {
+        "geo": [{ "map": "world" }],
+        "series": [
+          {
+            "type": "lines",
+            "name": "Beijing",
+            "coordinateSystem": "geo",
+            "zlevel": 2.0,
+            "effect": {
+              "show": true,
+              "period": 6.0,
+              "trailLength": 0.7,
+              "color": "#fff",
+              "symbol": "arrow",
+              "symbolSize": 3.0
+            },
+            "lineStyle": { "width": 1.0, "curveness": 0.2 },
+            "data": [
+              {
+                "name": "Beijing > Shanghai",
+                "coords": [[116.4551, 40.2539], [121.4648, 31.2891]]
+              },
+              {
+                "coords": [[116.4551, 40.2539], [113.5107, 23.2196]],
+                "value": 95
+              }
+            ]
+          }
+        ]
+      }
+    );
+
+    let chart = Chart::new().geo(Geo::new().map("world")).series(
+        Lines::new()
+            .name("Beijing")
+            .coordinate_system(CoordinateSystem::Geo)
+            .zlevel(2)
+            .effect(
+                LinesEffect::new()
+                    .show(true)
+                    .period(6)
+                    .trail_length(0.7)
+                    .color("#fff")
+                    .symbol(Symbol::Arrow)
+                    .symbol_size(3),
+            )
+            .line_style(LineStyle::new().width(1).curveness(0.2))
+            .data(vec![
+                LinesData::new()
+                    .name("Beijing > Shanghai")
+                    .coords(vec![(116.4551, 40.2539), (121.4648, 31.2891)]),
+                LinesData::from(vec![(116.4551, 40.2539), (113.5107, 23.2196)]).value(95),
+            ]),
+    );
+
+    let charming_json = serde_json::from_str::<serde_json::Value>(&chart.to_string()).unwrap();
+    assert_json_eq!(echarts_json, charming_json);
+
+    let deserialized: Chart = serde_json::from_value(charming_json).unwrap();
+    assert_eq!(chart, deserialized);
+}
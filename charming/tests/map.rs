@@ -0,0 +1,95 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    component::{Geo, GeoMap, GeoMapOpt, VisualMap},
+    element::{Emphasis, ItemStyle, Label, ScaleLimit},
+    series::Map,
+    Chart,
+};
+use serde_json::json;
+
+#[test]
+fn choropleth_map() {
+    let echarts_json = json!(
+      {
+        "geo": [
+          {
+            "map": "USA",
+            "roam": true,
+            "scaleLimit": { "min": 1.0, "max": 4.0 }
+          }
+        ],
+        "visualMap": [
+          {
+            "min": 500000.0,
+            "max": 38000000.0,
+            "calculable": true
+          }
+        ],
+        "series": [
+          {
+            "type": "map",
+            "name": "USA PopEstimates",
+            "map": "USA",
+            "geoIndex": 0.0,
+            "nameMap": { "Alaska": "AK", "Texas": "TX" },
+            "emphasis": { "label": { "show": true } },
+            "itemStyle": { "borderColor": "#fff" },
+            "data": [
+              { "name": "Alaska", "value": 731449 },
+              { "name": "Texas", "value": 26059203 }
+            ]
+          }
+        ]
+      }
+    );
+
+    let chart = Chart::new()
+        .geo(
+            Geo::new()
+                .map("USA")
+                .roam(true)
+                .scale_limit(ScaleLimit::new().min(1.0).max(4.0)),
+        )
+        .visual_map(
+            VisualMap::new()
+                .min(500000.0)
+                .max(38000000.0)
+                .calculable(true),
+        )
+        .series(
+            Map::new()
+                .name("USA PopEstimates")
+                .map("USA")
+                .geo_index(0.0)
+                .name_map(vec![("Alaska", "AK"), ("Texas", "TX")])
+                .emphasis(Emphasis::new().label(Label::new().show(true)))
+                .item_style(ItemStyle::new().border_color("#fff"))
+                .data(vec![(731449, "Alaska"), (26059203, "Texas")]),
+        );
+
+    let charming_json = serde_json::from_str::<serde_json::Value>(&chart.to_string()).unwrap();
+    assert_json_eq!(echarts_json, charming_json);
+
+    let deserialized: Chart = serde_json::from_value(charming_json).unwrap();
+    assert_eq!(chart, deserialized);
+}
+
+#[test]
+fn geo_maps_of_timeline_charts() {
+    let usa = || {
+        GeoMap::new().name("USA").opt(GeoMapOpt::GeoJson {
+            value: json!({ "type": "FeatureCollection", "features": [] }),
+            special_areas: serde_json::Value::Null,
+        })
+    };
+    let world = GeoMap::from(("world", "<svg></svg>"));
+
+    let chart = Chart::new()
+        .base_option(Chart::new().geo_map(usa()))
+        .options(vec![
+            Chart::new().geo_map(usa()),
+            Chart::new().geo_map(world.clone()),
+        ]);
+
+    assert_eq!(chart.geo_maps(), vec![&usa(), &world]);
+}
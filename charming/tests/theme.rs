@@ -0,0 +1,111 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    element::{JsFunction, Label, LineStyle, TextStyle},
+    series::Line,
+    theme::{Theme, ThemeDefinition},
+    Chart, HtmlRenderer,
+};
+use serde_json::json;
+
+#[test]
+fn theme_definition_from_builder_export() {
+    let exported = r##"{
+        "version": 1,
+        "themeName": "customed",
+        "theme": {
+            "color": ["#c12e34", "#e6b600"],
+            "backgroundColor": "rgba(0,0,0,0)",
+            "textStyle": {},
+            "line": { "smooth": false, "symbolSize": "4" },
+            "categoryAxis": { "axisLine": { "show": true } }
+        }
+    }"##;
+
+    let theme = ThemeDefinition::from_json("brand", exported).unwrap();
+    assert_eq!(theme.name(), "brand");
+
+    let expected = ThemeDefinition::new("brand")
+        .color(vec!["#c12e34", "#e6b600"])
+        .background_color("rgba(0,0,0,0)")
+        .text_style(TextStyle::new())
+        .defaults("line", json!({ "smooth": false, "symbolSize": "4" }))
+        .unwrap()
+        .defaults("categoryAxis", json!({ "axisLine": { "show": true } }))
+        .unwrap();
+    assert_eq!(theme, expected);
+}
+
+#[test]
+fn theme_definition_serialization() {
+    let theme = ThemeDefinition::new("brand")
+        .color(vec!["#0b3c5d", "#328cc1"])
+        .background_color("#fafafa")
+        .defaults("line", Line::new().line_style(LineStyle::new().width(3)))
+        .unwrap();
+
+    assert_json_eq!(
+        serde_json::to_value(&theme).unwrap(),
+        json!({
+          "color": ["#0b3c5d", "#328cc1"],
+          "backgroundColor": "#fafafa",
+          "line": { "lineStyle": { "width": 3.0 } }
+        })
+    );
+}
+
+#[test]
+fn html_renderer_registers_theme_definition() {
+    let theme = ThemeDefinition::new("brand").color(vec!["#0b3c5d"]);
+    let html = HtmlRenderer::new("theme", 800, 600)
+        .theme(Theme::from(theme))
+        .render(&Chart::new())
+        .unwrap();
+
+    let register = html
+        .find(r##"echarts.registerTheme("brand", {"color":["#0b3c5d"]});"##)
+        .expect("theme should be registered");
+    let init = html.find("echarts.init(").unwrap();
+    assert!(register < init);
+    assert!(html.contains(r#"document.getElementById('chart'), "brand", {"#));
+}
+
+#[test]
+fn theme_definition_functions() {
+    let theme = ThemeDefinition::new("brand")
+        .defaults(
+            "line",
+            Line::new().label(Label::new().formatter(JsFunction::new_with_args(
+                "params",
+                "return params.value + ' km';",
+            ))),
+        )
+        .unwrap();
+    let html = HtmlRenderer::new("theme", 800, 600)
+        .theme(Theme::from(theme))
+        .render(&Chart::new())
+        .unwrap();
+
+    assert!(html.contains(
+        r#"{"line":{"label":{"formatter":function(params) { return params.value + ' km'; }}}}"#
+    ));
+    assert!(!html.contains("#*#*#*#"));
+}
+
+#[test]
+fn theme_definition_names_are_escaped() {
+    let name = "O'Brien & <Co></script>";
+    let html = HtmlRenderer::new("theme", 800, 600)
+        .theme(Theme::from(ThemeDefinition::new(name)))
+        .render(&Chart::new())
+        .unwrap();
+
+    let literal = r#""O'Brien & <Co><\/script>""#;
+    assert!(html.contains(&format!("echarts.registerTheme({literal}, {{}});")));
+    assert!(html.contains(&format!("document.getElementById('chart'), {literal}, {{")));
+    assert!(!html.contains("</script>\""));
+}
+
+#[test]
+fn theme_definition_invalid_json() {
+    assert!(ThemeDefinition::from_json("brand", "{ not json").is_err());
+}
@@ -0,0 +1,77 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    component::{Axis, Timeline, Title},
+    element::AxisType,
+    series::Bar,
+    Chart,
+};
+use serde_json::json;
+
+#[test]
+fn timeline_base_option_and_options() {
+    let echarts_json = json!(
+      {
+        "baseOption": {
+          "timeline": {
+            "axisType": "category",
+            "autoPlay": true,
+            "loop": false,
+            "playInterval": 1000.0,
+            "data": ["2002", "2003"]
+          },
+          "xAxis": {
+            "type": "category",
+            "data": ["Beijing", "Tianjin"]
+          },
+          "yAxis": {
+            "type": "value"
+          },
+          "series": [{ "type": "bar" }]
+        },
+        "options": [
+          {
+            "title": [{ "text": "2002" }],
+            "series": [{ "type": "bar", "data": [4315, 2150] }]
+          },
+          {
+            "title": [{ "text": "2003" }],
+            "series": [{ "type": "bar", "data": [5007, 2578] }]
+          }
+        ]
+      }
+    );
+
+    let chart = Chart::new()
+        .base_option(
+            Chart::new()
+                .timeline(
+                    Timeline::new()
+                        .axis_type(AxisType::Category)
+                        .auto_play(true)
+                        .loop_(false)
+                        .play_interval(1000)
+                        .data(vec!["2002", "2003"]),
+                )
+                .x_axis(
+                    Axis::new()
+                        .type_(AxisType::Category)
+                        .data(vec!["Beijing", "Tianjin"]),
+                )
+                .y_axis(Axis::new().type_(AxisType::Value))
+                .series(Bar::new()),
+        )
+        .options(vec![
+            Chart::new()
+                .title(Title::new().text("2002"))
+                .series(Bar::new().data(vec![4315, 2150])),
+            Chart::new()
+                .title(Title::new().text("2003"))
+                .series(Bar::new().data(vec![5007, 2578])),
+        ]);
+
+    let charming_json = serde_json::from_str::<serde_json::Value>(&chart.to_string()).unwrap();
+    assert_json_eq!(echarts_json, charming_json);
+
+    let deserialized: Chart = serde_json::from_value(charming_json).unwrap();
+    assert_eq!(chart, deserialized);
+}
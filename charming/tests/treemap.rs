@@ -0,0 +1,108 @@
+use assert_json_diff::assert_json_eq;
+use charming::{
+    element::Label,
+    series::{
+        Treemap, TreemapBreadcrumb, TreemapItemStyle, TreemapLevel, TreemapNode, TreemapNodeClick,
+    },
+    Chart,
+};
+use serde_json::json;
+
+#[test]
+fn treemap_with_levels() {
+    let echarts_json = json!(
+      {
+        "series": [
+          {
+            "type": "treemap",
+            "name": "Disk Usage",
+            "leafDepth": 1.0,
+            "drillDownIcon": "▶",
+            "roam": false,
+            "nodeClick": false,
+            "visualDimension": 1.0,
+            "breadcrumb": { "show": false },
+            "levels": [
+              {
+                "itemStyle": {
+                  "borderColor": "#777",
+                  "borderWidth": 0.0,
+                  "gapWidth": 1.0
+                },
+                "upperLabel": { "show": false }
+              },
+              {
+                "colorSaturation": [0.35, 0.5],
+                "itemStyle": {
+                  "borderWidth": 5.0,
+                  "gapWidth": 1.0,
+                  "borderColorSaturation": 0.6
+                }
+              }
+            ],
+            "data": [
+              {
+                "name": "Accounts",
+                "value": [180.0, 12.0],
+                "color": ["#c23531", "#2f4554"],
+                "children": [
+                  { "name": "Access", "value": 76.0 },
+                  {
+                    "name": "Sessions",
+                    "value": 104.0,
+                    "itemStyle": { "color": "#61a0a8" }
+                  }
+                ]
+              },
+              { "name": "Accessibility", "value": 40.0 }
+            ]
+          }
+        ]
+      }
+    );
+
+    let chart = Chart::new().series(
+        Treemap::new()
+            .name("Disk Usage")
+            .leaf_depth(1)
+            .drill_down_icon("▶")
+            .roam(false)
+            .node_click(TreemapNodeClick::None)
+            .visual_dimension(1)
+            .breadcrumb(TreemapBreadcrumb::new().show(false))
+            .levels(vec![
+                TreemapLevel::new()
+                    .item_style(
+                        TreemapItemStyle::new()
+                            .border_color("#777")
+                            .border_width(0)
+                            .gap_width(1),
+                    )
+                    .upper_label(Label::new().show(false)),
+                TreemapLevel::new()
+                    .color_saturation((0.35, 0.5))
+                    .item_style(
+                        TreemapItemStyle::new()
+                            .border_width(5)
+                            .gap_width(1)
+                            .border_color_saturation(0.6),
+                    ),
+            ])
+            .data(vec![
+                TreemapNode::new("Accounts")
+                    .value(vec![180.0, 12.0])
+                    .color(vec!["#c23531", "#2f4554"])
+                    .children(vec![
+                        ("Access", 76.0).into(),
+                        ("Sessions", 104.0, "#61a0a8").into(),
+                    ]),
+                ("Accessibility", 40.0).into(),
+            ]),
+    );
+
+    let charming_json = serde_json::from_str::<serde_json::Value>(&chart.to_string()).unwrap();
+    assert_json_eq!(echarts_json, charming_json);
+
+    let deserialized: Chart = serde_json::from_value(charming_json).unwrap();
+    assert_eq!(chart, deserialized);
+}
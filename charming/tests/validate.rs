@@ -0,0 +1,203 @@
+use charming::{
+    component::{Axis, Geo, Grid, RadarCoordinate, SingleAxis, VisualMap},
+    datatype::{CompositeValue, Dataset, Source},
+    element::{CoordinateSystem, DimensionEncode},
+    series::{Bar, Line, Lines, LinesData, Radar, Scatter, ThemeRiver},
+    validate::{Diagnostic, Severity},
+    Chart,
+};
+
+fn paths(diagnostics: &[Diagnostic]) -> Vec<&str> {
+    diagnostics.iter().map(|d| d.path.as_str()).collect()
+}
+
+fn dataset() -> Dataset {
+    Dataset::new().source(
+        Source::from(vec![
+            vec![
+                CompositeValue::from("product"),
+                CompositeValue::from("sales"),
+            ],
+            vec![CompositeValue::from("tea"), CompositeValue::from(3)],
+        ])
+        .id("products"),
+    )
+}
+
+#[test]
+fn valid_chart() {
+    let chart = Chart::new()
+        .x_axis(Axis::new().data(vec!["Mon", "Tue", "Wed"]))
+        .y_axis(Axis::new())
+        .series(Bar::new().data(vec![1, 2, 3]))
+        .series(Line::new().data(vec![3, 2, 1]))
+        .visual_map(VisualMap::new().series_index(1));
+
+    assert_eq!(chart.validate(), vec![]);
+}
+
+#[test]
+fn dangling_axis_and_grid_indices() {
+    let chart = Chart::new()
+        .grid(Grid::new())
+        .x_axis(Axis::new().grid_index(1))
+        .y_axis(Axis::new())
+        .series(Line::new().x_axis_index(1).y_axis_index(0));
+
+    let diagnostics = chart.validate();
+    assert_eq!(
+        paths(&diagnostics),
+        vec!["xAxis.gridIndex", "series[0].xAxisIndex"]
+    );
+    assert!(diagnostics.iter().all(|d| d.severity == Severity::Error));
+    assert_eq!(
+        diagnostics[1].message,
+        "xAxisIndex 1 refers to a missing xAxis, the chart has 1"
+    );
+}
+
+#[test]
+fn missing_components() {
+    let chart = Chart::new()
+        .series(Bar::new().data(vec![1, 2]))
+        .series(Radar::new())
+        .series(Bar::new().coordinate_system(CoordinateSystem::Polar));
+
+    assert_eq!(
+        paths(&chart.validate()),
+        vec!["series[0]", "series[0]", "series[1]", "series[2]"]
+    );
+
+    let chart = Chart::new()
+        .radar(RadarCoordinate::new())
+        .series(Radar::new().radar_index(1));
+    assert_eq!(paths(&chart.validate()), vec!["series[0].radarIndex"]);
+}
+
+#[test]
+fn lines_default_to_geo() {
+    let lines = || {
+        Lines::new().data(vec![LinesData::from(vec![
+            (116.4551, 40.2539),
+            (121.4648, 31.2891),
+        ])])
+    };
+
+    let chart = Chart::new().geo(Geo::new().map("china")).series(lines());
+    assert_eq!(chart.validate(), vec![]);
+
+    let chart = Chart::new().series(lines());
+    let diagnostics = chart.validate();
+    assert_eq!(paths(&diagnostics), vec!["series[0]"]);
+    assert!(diagnostics[0].message.contains("geo"));
+}
+
+#[test]
+fn theme_rivers_need_a_single_axis() {
+    let chart = Chart::new()
+        .single_axis(SingleAxis::new())
+        .series(ThemeRiver::new().coordinate_system(CoordinateSystem::Single));
+    assert_eq!(chart.validate(), vec![]);
+
+    let diagnostics = Chart::new().series(ThemeRiver::new()).validate();
+    assert_eq!(paths(&diagnostics), vec!["series[0]"]);
+    assert_eq!(
+        diagnostics[0].message,
+        "a themeRiver series needs a singleAxis component, the chart has none"
+    );
+}
+
+#[test]
+fn incompatible_coordinate_system() {
+    let chart = Chart::new()
+        .x_axis(Axis::new())
+        .y_axis(Axis::new())
+        .series(Bar::new().coordinate_system(CoordinateSystem::Calendar));
+
+    let diagnostics = chart.validate();
+    assert_eq!(
+        diagnostics[0],
+        Diagnostic {
+            path: "series[0].coordinateSystem".to_string(),
+            severity: Severity::Error,
+            message: "a bar series cannot be drawn on the calendar coordinate system".to_string(),
+        }
+    );
+}
+
+#[test]
+fn dangling_dataset_references() {
+    let chart = Chart::new()
+        .dataset(dataset())
+        .x_axis(Axis::new())
+        .y_axis(Axis::new())
+        .series(Line::new().dataset_id("orders"))
+        .series(Scatter::new().dataset_index(2))
+        .visual_map(VisualMap::new().series_index(2));
+
+    assert_eq!(
+        paths(&chart.validate()),
+        vec![
+            "series[0].datasetId",
+            "series[1].datasetIndex",
+            "visualMap[0].seriesIndex"
+        ]
+    );
+}
+
+#[test]
+fn unknown_encode_dimensions() {
+    let chart = Chart::new()
+        .dataset(dataset())
+        .x_axis(Axis::new())
+        .y_axis(Axis::new())
+        .series(
+            Line::new()
+                .dataset_id("products")
+                .encode(DimensionEncode::new().x("product").y("sale")),
+        );
+
+    let diagnostics = chart.validate();
+    assert_eq!(paths(&diagnostics), vec!["series[0].encode.y"]);
+    assert_eq!(
+        diagnostics[0].message,
+        "unknown dimension \"sale\", the dimensions are product, sales"
+    );
+}
+
+#[test]
+fn data_length_vs_categories() {
+    let chart = Chart::new()
+        .x_axis(Axis::new().data(vec!["Mon", "Tue", "Wed"]))
+        .y_axis(Axis::new())
+        .series(Bar::new().data(vec![1, 2]))
+        .series(Scatter::new().data(vec![vec![0, 1], vec![2, 3]]));
+
+    let diagnostics = chart.validate();
+    assert_eq!(paths(&diagnostics), vec!["series[0].data"]);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "warning at `series[0].data`: the series has 2 data items but xAxis has 3 categories"
+    );
+}
+
+#[test]
+fn timeline_frames() {
+    let chart = Chart::new()
+        .base_option(
+            Chart::new()
+                .x_axis(Axis::new().data(vec!["Q1", "Q2"]))
+                .y_axis(Axis::new())
+                .series(Bar::new()),
+        )
+        .options(vec![
+            Chart::new().series(Bar::new().data(vec![1, 2])),
+            Chart::new().series(Bar::new().y_axis_index(1).data(vec![1, 2])),
+        ]);
+
+    assert_eq!(
+        paths(&chart.validate()),
+        vec!["options[1].series[0].yAxisIndex"]
+    );
+}
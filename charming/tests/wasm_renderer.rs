@@ -0,0 +1,200 @@
+#![cfg(feature = "wasm")]
+
+use assert_json_diff::assert_json_eq;
+use charming::{
+    datatype::DataPoint, EventBatch, EventParams, LineBuffer, Selected, SeriesSelection,
+    UpdateOptions,
+};
+use serde_json::json;
+
+#[test]
+fn update_options() {
+    assert_json_eq!(
+        serde_json::to_value(UpdateOptions::new()).unwrap(),
+        json!({})
+    );
+    assert_json_eq!(
+        serde_json::to_value(
+            UpdateOptions::new()
+                .replace_merge("series")
+                .replace_merge("xAxis")
+                .lazy_update(true)
+                .silent(true)
+        )
+        .unwrap(),
+        json!({ "replaceMerge": ["series", "xAxis"], "lazyUpdate": true, "silent": true })
+    );
+    assert_json_eq!(
+        serde_json::to_value(UpdateOptions::new().not_merge(true)).unwrap(),
+        json!({ "notMerge": true })
+    );
+}
+
+#[test]
+fn line_buffer_keeps_last_points() {
+    let mut buffer = LineBuffer::new(2, 3);
+    assert!(buffer.is_empty());
+
+    buffer.push(vec![0, 10]);
+    buffer.extend(vec![vec![1, 11], vec![2, 12], vec![3, 13]]);
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(
+        buffer.points().cloned().collect::<Vec<_>>(),
+        vec![
+            DataPoint::from(vec![1, 11]),
+            DataPoint::from(vec![2, 12]),
+            DataPoint::from(vec![3, 13])
+        ]
+    );
+
+    assert_json_eq!(
+        buffer.option(),
+        json!({ "series": [{}, {}, { "data": [[1, 11], [2, 12], [3, 13]] }] })
+    );
+
+    buffer.clear();
+    assert_json_eq!(
+        buffer.option(),
+        json!({ "series": [{}, {}, { "data": [] }] })
+    );
+}
+
+#[test]
+fn empty_line_buffer() {
+    let mut buffer = LineBuffer::new(0, 0);
+    buffer.push(1);
+    assert!(buffer.is_empty());
+}
+
+fn event_params(params: serde_json::Value) -> EventParams {
+    serde_json::from_value(params).unwrap()
+}
+
+#[test]
+fn click_event_params() {
+    let params = event_params(json!({
+        "type": "click",
+        "componentType": "series",
+        "componentIndex": 0,
+        "seriesType": "bar",
+        "seriesIndex": 1,
+        "seriesName": "Sales",
+        "dataIndex": 2,
+        "name": "Wed",
+        "value": 150,
+        "data": { "value": 150, "name": "Wed" },
+        "color": "#5470c6",
+        "event": {}
+    }));
+
+    assert_eq!(params.type_.as_deref(), Some("click"));
+    assert_eq!(params.component_type.as_deref(), Some("series"));
+    assert_eq!(params.series_type.as_deref(), Some("bar"));
+    assert_eq!(params.series_index, Some(1));
+    assert_eq!(params.series_name.as_deref(), Some("Sales"));
+    assert_eq!(params.data_index, Some(2));
+    assert_eq!(params.name.as_deref(), Some("Wed"));
+    assert_eq!(params.value, Some(json!(150)));
+    assert_eq!(params.selected, None);
+    assert!(params.batch.is_empty());
+}
+
+#[test]
+fn selection_event_params() {
+    let legend = event_params(json!({
+        "type": "legendselectchanged",
+        "name": "Costs",
+        "selected": { "Sales": true, "Costs": false }
+    }));
+    assert_eq!(legend.name.as_deref(), Some("Costs"));
+    assert_eq!(
+        legend.selected,
+        Some(Selected::Legend(
+            [("Sales".to_string(), true), ("Costs".to_string(), false)].into()
+        ))
+    );
+
+    let series = event_params(json!({
+        "type": "selectchanged",
+        "fromAction": "select",
+        "isFromClick": true,
+        "selected": [{ "seriesIndex": 0, "dataIndex": [1, 3] }]
+    }));
+    assert_eq!(
+        series.selected,
+        Some(Selected::Series(vec![SeriesSelection {
+            series_index: 0,
+            series_name: None,
+            data_index: vec![1, 3],
+        }]))
+    );
+}
+
+#[test]
+fn batch_event_params() {
+    let brush = event_params(json!({
+        "type": "brushselected",
+        "batch": [{
+            "brushId": "brush-0",
+            "brushIndex": 0,
+            "brushName": "",
+            "areas": [{
+                "brushType": "rect",
+                "range": [[10, 100], [20, 200]],
+                "coordRange": [[0, 5], [10, 50]],
+                "panelId": "grid--0"
+            }],
+            "selected": [
+                { "seriesIndex": 0, "seriesName": "Sales", "dataIndex": [0, 2] },
+                { "seriesIndex": 1, "seriesName": "Costs", "dataIndex": [] }
+            ]
+        }]
+    }));
+    let batch = &brush.batch[0];
+    assert_eq!(batch.brush_id.as_deref(), Some("brush-0"));
+    assert_eq!(batch.areas[0].brush_type.as_deref(), Some("rect"));
+    assert_eq!(batch.areas[0].coord_range, Some(json!([[0, 5], [10, 50]])));
+    assert_eq!(batch.selected.len(), 2);
+    assert_eq!(batch.selected[0].data_index, vec![0, 2]);
+    assert_eq!(batch.selected[1].series_name.as_deref(), Some("Costs"));
+
+    let slider = event_params(json!({
+        "type": "datazoom",
+        "dataZoomId": "slider",
+        "start": 20,
+        "end": 80
+    }));
+    assert_eq!((slider.start, slider.end), (Some(20.0), Some(80.0)));
+
+    let inside = event_params(json!({
+        "type": "datazoom",
+        "batch": [{ "dataZoomId": "inside", "start": 10.5, "end": 60, "startValue": 3, "endValue": "Sun" }]
+    }));
+    assert_eq!(
+        inside.batch,
+        vec![EventBatch {
+            data_zoom_id: Some("inside".to_string()),
+            start: Some(10.5),
+            end: Some(60.0),
+            start_value: Some(json!(3)),
+            end_value: Some(json!("Sun")),
+            ..Default::default()
+        }]
+    );
+}
+
+#[test]
+fn unexpected_selection_shapes_are_ignored() {
+    let params = event_params(json!({
+        "type": "brushselected",
+        "seriesIndex": 0,
+        "selected": "everything",
+        "batch": [{ "areas": "not an array" }]
+    }));
+    assert_eq!(params.series_index, Some(0));
+    assert_eq!(params.selected, None);
+    assert!(params.batch.is_empty());
+
+    let params = event_params(json!({ "type": "legendselectchanged", "selected": null }));
+    assert_eq!(params.selected, None);
+}
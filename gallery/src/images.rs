@@ -69,7 +69,8 @@ fn main() {
             Theme::Shine => "shine",
             Theme::PurplePassion => "purple-passion",
             Theme::Halloween => "halloween",
-            Theme::Custom(_, _) => todo!(),
+            Theme::Custom(name, _) => name,
+            Theme::Definition(ref definition) => definition.name(),
         };
 
         println!("Rendering theme/{theme_name}");